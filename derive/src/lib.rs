@@ -0,0 +1,468 @@
+#![deny(missing_docs)]
+
+//! Custom derives for the traits provided by the `pod` crate.
+//!
+//! These are usually used through the `derive` feature of `pod`, which
+//! re-exports them alongside the traits they implement.
+
+extern crate proc_macro;
+extern crate proc_macro2;
+#[macro_use]
+extern crate quote;
+#[macro_use]
+extern crate syn;
+
+use proc_macro::TokenStream;
+use proc_macro2::{TokenStream as TokenStream2, TokenTree};
+use quote::ToTokens;
+use syn::{Data, DataEnum, DeriveInput, Error, Field, Fields, Generics, Ident, Index, Member, Result, Type};
+
+/// Derives `Pod` for a struct or union.
+///
+/// The type must be `#[repr(C)]`, `#[repr(transparent)]` or `#[repr(packed)]`,
+/// and every field must itself be `Pod`. Type parameters are bounded by `Pod`.
+///
+/// The `Zeroable` supertrait is implemented as well, so it must not also be
+/// derived.
+///
+/// ```compile_fail
+/// #[macro_use] extern crate pod_derive;
+/// extern crate pod;
+///
+/// #[derive(Pod)]
+/// struct NoRepr {
+///     value: u32,
+/// }
+/// # fn main() { }
+/// ```
+///
+/// ```compile_fail
+/// #[macro_use] extern crate pod_derive;
+/// extern crate pod;
+///
+/// #[derive(Pod)]
+/// #[repr(C)]
+/// struct NotPod {
+///     value: &'static u32,
+/// }
+/// # fn main() { }
+/// ```
+#[proc_macro_derive(Pod)]
+pub fn derive_pod(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_pod(&input).unwrap_or_else(compile_error).into()
+}
+
+fn expand_pod(input: &DeriveInput) -> Result<TokenStream2> {
+    let fields = data_fields(input, "Pod")?;
+    let repr = Repr::parse(input)?;
+    if !repr.is_stable() {
+        return Err(Error::new_spanned(&input.ident,
+            "Pod can only be derived for #[repr(C)], #[repr(transparent)] or #[repr(packed)] types"
+        ))
+    }
+
+    let name = &input.ident;
+    let generics = add_bounds(&input.generics, quote!(::pod::Pod));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let tys = fields.iter().map(|f| &f.ty);
+
+    Ok(quote! {
+        unsafe impl #impl_generics ::pod::Zeroable for #name #ty_generics #where_clause { }
+
+        unsafe impl #impl_generics ::pod::Pod for #name #ty_generics #where_clause {
+            #[doc(hidden)]
+            fn __assert_pod() {
+                fn __assert_field<T: ::pod::Pod>() { }
+                #( __assert_field::<#tys>(); )*
+            }
+        }
+    })
+}
+
+/// Derives `Zeroable` for a struct, union or fieldless enum.
+///
+/// Every field must itself be `Zeroable`. Fields that use a type parameter
+/// are bounded by `Zeroable` in place of the parameter itself, so a field such
+/// as `Option<&'a T>` places no requirement on `T`. Enums must have an integer
+/// representation such as `#[repr(u8)]` and a variant with a discriminant of
+/// zero.
+///
+/// ```compile_fail
+/// #[macro_use] extern crate pod_derive;
+/// extern crate pod;
+///
+/// #[derive(Zeroable)]
+/// #[repr(u8)]
+/// enum NonZero {
+///     A = 1,
+///     B,
+/// }
+/// # fn main() { }
+/// ```
+#[proc_macro_derive(Zeroable)]
+pub fn derive_zeroable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_zeroable(&input).unwrap_or_else(compile_error).into()
+}
+
+fn expand_zeroable(input: &DeriveInput) -> Result<TokenStream2> {
+    let name = &input.ident;
+
+    match input.data {
+        Data::Enum(ref data) => {
+            let repr = Repr::parse(input)?;
+            let int = match repr.int {
+                Some(ref int) => int,
+                None => return Err(Error::new_spanned(&input.ident,
+                    "Zeroable can only be derived for enums with an integer #[repr]"
+                )),
+            };
+            if let Some(variant) = data.variants.iter().find(|v| !v.fields.is_empty()) {
+                return Err(Error::new_spanned(&variant.ident,
+                    "Zeroable can only be derived for fieldless enums"
+                ))
+            }
+
+            let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+            let variants = data.variants.iter().map(|v| &v.ident);
+            let message = format!("`{}` has no variant with a discriminant of zero", name);
+            Ok(quote! {
+                unsafe impl #impl_generics ::pod::Zeroable for #name #ty_generics #where_clause { }
+
+                const _: () = assert!(false #( || #name::#variants as #int == 0 )*, #message);
+            })
+        },
+        _ => {
+            let tys = &data_fields(input, "Zeroable")?.into_iter().map(|f| &f.ty).collect::<Vec<_>>();
+            // Bound the fields rather than the parameters, as a zeroed `Option<&T>`
+            // is valid for any `T`
+            let generics = add_field_bounds(&input.generics, tys, quote!(::pod::Zeroable));
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+            Ok(quote! {
+                unsafe impl #impl_generics ::pod::Zeroable for #name #ty_generics #where_clause {
+                    #[doc(hidden)]
+                    fn __assert_zeroable() {
+                        fn __assert_field<T: ::pod::Zeroable>() { }
+                        #( __assert_field::<#tys>(); )*
+                    }
+                }
+            })
+        },
+    }
+}
+
+/// Derives `NoPadding` for a struct.
+///
+/// The struct must have a stable layout as with `#[derive(Pod)]`, and every
+/// field must itself be `NoPadding`. The sum of the sizes of the fields is
+/// checked against the size of the struct at compile time, or when the
+/// impl is first used for generic structs.
+///
+/// ```compile_fail
+/// #[macro_use] extern crate pod_derive;
+/// extern crate pod;
+///
+/// #[derive(Pod, NoPadding)]
+/// #[repr(C)]
+/// struct Padded {
+///     a: u8,
+///     b: u32,
+/// }
+/// # fn main() { }
+/// ```
+#[proc_macro_derive(NoPadding)]
+pub fn derive_no_padding(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_no_padding(&input).unwrap_or_else(compile_error).into()
+}
+
+fn expand_no_padding(input: &DeriveInput) -> Result<TokenStream2> {
+    let fields = match input.data {
+        Data::Struct(ref data) => data.fields.iter().collect::<Vec<_>>(),
+        _ => return Err(Error::new_spanned(&input.ident,
+            "NoPadding can only be derived for structs"
+        )),
+    };
+    let repr = Repr::parse(input)?;
+    if !repr.is_stable() {
+        return Err(Error::new_spanned(&input.ident,
+            "NoPadding can only be derived for #[repr(C)], #[repr(transparent)] or #[repr(packed)] types"
+        ))
+    }
+
+    let name = &input.ident;
+    let generics = add_bounds(&input.generics, quote!(::pod::NoPadding));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let tys = &fields.iter().map(|f| &f.ty).collect::<Vec<_>>();
+    let message = format!("`{}` contains padding", name);
+    let assert = if input.generics.params.is_empty() {
+        quote! {
+            const _: () = <#name as ::pod::NoPadding>::__ASSERT_NO_PADDING;
+        }
+    } else {
+        quote! { }
+    };
+
+    Ok(quote! {
+        unsafe impl #impl_generics ::pod::NoPadding for #name #ty_generics #where_clause {
+            #[doc(hidden)]
+            const __ASSERT_NO_PADDING: () = {
+                #( let () = <#tys as ::pod::NoPadding>::__ASSERT_NO_PADDING; )*
+                assert!(
+                    ::pod::__derive::size_of::<Self>() == 0 #( + ::pod::__derive::size_of::<#tys>() )*,
+                    #message
+                );
+            };
+        }
+
+        #assert
+    })
+}
+
+/// Derives `CheckedPod` for a fieldless enum or a struct.
+///
+/// Enums must have an integer representation such as `#[repr(u8)]`, and are
+/// validated against the discriminants of their variants. Structs must have a
+/// stable layout as with `#[derive(Pod)]`, and are validated field by field.
+///
+/// ```compile_fail
+/// #[macro_use] extern crate pod_derive;
+/// extern crate pod;
+///
+/// #[derive(CheckedPod)]
+/// enum NoRepr {
+///     A,
+///     B,
+/// }
+/// # fn main() { }
+/// ```
+#[proc_macro_derive(CheckedPod)]
+pub fn derive_checked_pod(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_checked_pod(&input).unwrap_or_else(compile_error).into()
+}
+
+fn expand_checked_pod(input: &DeriveInput) -> Result<TokenStream2> {
+    let repr = Repr::parse(input)?;
+    let validate = match input.data {
+        Data::Enum(ref data) => validate_enum(input, data, &repr)?,
+        Data::Struct(ref data) => {
+            if !repr.is_stable() {
+                return Err(Error::new_spanned(&input.ident,
+                    "CheckedPod can only be derived for #[repr(C)], #[repr(transparent)] or #[repr(packed)] structs"
+                ))
+            }
+            validate_fields(&data.fields)
+        },
+        Data::Union(_) => return Err(Error::new_spanned(&input.ident,
+            "CheckedPod cannot be derived for unions"
+        )),
+    };
+
+    let name = &input.ident;
+    let generics = add_bounds(&input.generics, quote!(::pod::CheckedPod));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        unsafe impl #impl_generics ::pod::CheckedPod for #name #ty_generics #where_clause {
+            fn validate(bytes: &[u8]) -> ::pod::__derive::Result<(), ::pod::CheckedError> {
+                #validate
+            }
+        }
+    })
+}
+
+fn validate_enum(input: &DeriveInput, data: &DataEnum, repr: &Repr) -> Result<TokenStream2> {
+    let int = match repr.int {
+        Some(ref int) => int,
+        None => return Err(Error::new_spanned(&input.ident,
+            "CheckedPod can only be derived for enums with an integer #[repr]"
+        )),
+    };
+    if let Some(variant) = data.variants.iter().find(|v| !v.fields.is_empty()) {
+        return Err(Error::new_spanned(&variant.ident,
+            "CheckedPod can only be derived for fieldless enums"
+        ))
+    }
+
+    let name = &input.ident;
+    let variants = data.variants.iter().map(|v| &v.ident);
+    Ok(quote! {
+        let value = match <#int as ::pod::Pod>::from_bytes(bytes) {
+            Some(value) => value,
+            None => return Err(::pod::CheckedError::Size),
+        };
+        #(
+            if value == #name::#variants as #int {
+                return Ok(())
+            }
+        )*
+        Err(::pod::CheckedError::Invalid)
+    })
+}
+
+fn validate_fields(fields: &Fields) -> TokenStream2 {
+    let members = fields.iter().enumerate().map(|(i, f)| match f.ident {
+        Some(ref ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(Index::from(i)),
+    });
+    let tys = fields.iter().map(|f| &f.ty);
+    quote! {
+        if bytes.len() != ::pod::__derive::size_of::<Self>() {
+            return Err(::pod::CheckedError::Size)
+        }
+        #(
+            <#tys as ::pod::CheckedPod>::validate(
+                &bytes[::pod::__derive::offset_of!(Self, #members)..][..::pod::__derive::size_of::<#tys>()]
+            )?;
+        )*
+        Ok(())
+    }
+}
+
+/// Derives `EndianConvert` for a struct by converting each of its fields.
+///
+/// ```compile_fail
+/// #[macro_use] extern crate pod_derive;
+/// extern crate pod;
+///
+/// #[derive(EndianConvert)]
+/// struct NotConvertible {
+///     name: String,
+/// }
+/// # fn main() { }
+/// ```
+#[proc_macro_derive(EndianConvert)]
+pub fn derive_endian_convert(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_endian_convert(&input).unwrap_or_else(compile_error).into()
+}
+
+fn expand_endian_convert(input: &DeriveInput) -> Result<TokenStream2> {
+    let fields = match input.data {
+        Data::Struct(ref data) => &data.fields,
+        _ => return Err(Error::new_spanned(&input.ident,
+            "EndianConvert can only be derived for structs"
+        )),
+    };
+
+    let name = &input.ident;
+    let generics = add_bounds(&input.generics, quote!(::pod::EndianConvert));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let members = &fields.iter().enumerate().map(|(i, f)| match f.ident {
+        Some(ref ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(Index::from(i)),
+    }).collect::<Vec<_>>();
+
+    Ok(quote! {
+        impl #impl_generics ::pod::EndianConvert for #name #ty_generics #where_clause {
+            #[inline]
+            fn swap_bytes(self) -> Self {
+                Self {
+                    #( #members: ::pod::EndianConvert::swap_bytes(self.#members), )*
+                }
+            }
+        }
+    })
+}
+
+/// Reports an error without referring to `::core`, which 2015 edition crates
+/// may not have in scope.
+fn compile_error(err: Error) -> TokenStream2 {
+    err.into_iter().map(|err| {
+        let message = err.to_string();
+        quote_spanned!(err.span()=> compile_error! { #message })
+    }).collect()
+}
+
+/// Collects the fields of a struct or union, rejecting enums.
+fn data_fields<'a>(input: &'a DeriveInput, trait_name: &str) -> Result<Vec<&'a Field>> {
+    match input.data {
+        Data::Struct(ref data) => Ok(data.fields.iter().collect()),
+        Data::Union(ref data) => Ok(data.fields.named.iter().collect()),
+        Data::Enum(_) => Err(Error::new_spanned(&input.ident,
+            format!("{} cannot be derived for enums", trait_name)
+        )),
+    }
+}
+
+/// Adds `bound` to every type parameter.
+fn add_bounds(generics: &Generics, bound: TokenStream2) -> Generics {
+    let mut generics = generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(#bound));
+    }
+    generics
+}
+
+/// Adds `bound` to every field type that mentions a type parameter.
+fn add_field_bounds(generics: &Generics, tys: &[&Type], bound: TokenStream2) -> Generics {
+    let params = generics.type_params().map(|param| param.ident.to_string()).collect::<Vec<_>>();
+    let mut generics = generics.clone();
+    for ty in tys {
+        if mentions(ty.to_token_stream(), &params) {
+            generics.make_where_clause().predicates.push(parse_quote!(#ty: #bound));
+        }
+    }
+    generics
+}
+
+/// Returns whether `tokens` contain any of the identifiers in `params`.
+fn mentions(tokens: TokenStream2, params: &[String]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ref ident) => params.iter().any(|param| ident == param),
+        TokenTree::Group(ref group) => mentions(group.stream(), params),
+        _ => false,
+    })
+}
+
+/// The layout attributes of a type.
+#[derive(Default)]
+struct Repr {
+    c: bool,
+    transparent: bool,
+    packed: bool,
+    int: Option<Ident>,
+}
+
+impl Repr {
+    fn parse(input: &DeriveInput) -> Result<Self> {
+        let mut repr = Repr::default();
+        for attr in input.attrs.iter().filter(|a| a.path().is_ident("repr")) {
+            attr.parse_nested_meta(|meta| {
+                let ident = match meta.path.get_ident() {
+                    Some(ident) => ident.clone(),
+                    None => return Err(meta.error("unrecognized repr")),
+                };
+                match &ident.to_string()[..] {
+                    "C" => repr.c = true,
+                    "transparent" => repr.transparent = true,
+                    "packed" => {
+                        repr.packed = true;
+                        if meta.input.peek(syn::token::Paren) {
+                            let content;
+                            parenthesized!(content in meta.input);
+                            content.parse::<syn::LitInt>()?;
+                        }
+                    },
+                    "align" => {
+                        let content;
+                        parenthesized!(content in meta.input);
+                        content.parse::<syn::LitInt>()?;
+                    },
+                    "u8" | "u16" | "u32" | "u64" | "u128" | "usize" |
+                    "i8" | "i16" | "i32" | "i64" | "i128" | "isize" => repr.int = Some(ident),
+                    _ => return Err(meta.error("unrecognized repr")),
+                }
+                Ok(())
+            })?;
+        }
+        Ok(repr)
+    }
+
+    /// Whether the field layout is fully specified.
+    fn is_stable(&self) -> bool {
+        self.c || self.transparent || self.packed
+    }
+}
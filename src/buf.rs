@@ -0,0 +1,190 @@
+use core::mem::{align_of, size_of};
+use core::ops::{Deref, DerefMut};
+use core::slice::{from_raw_parts, from_raw_parts_mut};
+use core::fmt;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::io;
+use pod::{Pod, NoPadding};
+use error::CastError;
+
+/// A growable byte buffer whose storage is aligned for `A`.
+///
+/// Any `Pod` type with an alignment no greater than that of `A` can be cast
+/// from the start of the buffer. The storage is always zeroed before it is
+/// exposed, so `A` must be a non zero-sized type without padding.
+pub struct AlignedBuf<A: NoPadding = u64> {
+    storage: Vec<A>,
+    len: usize,
+}
+
+impl<A: NoPadding> AlignedBuf<A> {
+    /// Creates an empty buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `A` is zero-sized.
+    #[inline]
+    pub fn new() -> Self {
+        assert!(size_of::<A>() != 0, "AlignedBuf storage must not be zero-sized");
+
+        AlignedBuf {
+            storage: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Creates an empty buffer with room for at least `capacity` bytes.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut buf = Self::new();
+        buf.reserve(capacity);
+        buf
+    }
+
+    /// Returns the number of bytes the buffer can hold before its storage
+    /// has to grow.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.storage.len() * size_of::<A>()
+    }
+
+    /// Ensures that the buffer can hold at least `additional` more bytes.
+    pub fn reserve(&mut self, additional: usize) {
+        let len = self.len.checked_add(additional).expect("capacity overflow");
+        let units = len.div_ceil(size_of::<A>());
+        if units > self.storage.len() {
+            self.storage.resize_with(units, A::zeroed);
+        }
+    }
+
+    /// Resizes the buffer to `len` bytes, filling any new bytes with zeroes.
+    #[inline]
+    pub fn resize(&mut self, len: usize) {
+        if len > self.len {
+            let additional = len - self.len;
+            self.reserve(additional);
+            self.spare_mut()[..additional].fill(0);
+        }
+        self.len = len;
+    }
+
+    /// Shortens the buffer to `len` bytes, or does nothing if it is shorter.
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        self.len = self.len.min(len);
+    }
+
+    /// Empties the buffer, keeping its storage.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Appends bytes to the buffer.
+    #[inline]
+    pub fn extend_from_slice(&mut self, data: &[u8]) {
+        self.reserve(data.len());
+        self.spare_mut()[..data.len()].copy_from_slice(data);
+        self.len += data.len();
+    }
+
+    /// Fails if `P` is more strictly aligned than `A`, regardless of where
+    /// the storage happens to be allocated.
+    #[inline]
+    fn check_align<P>() -> Result<(), CastError> {
+        if align_of::<P>() <= align_of::<A>() {
+            Ok(())
+        } else {
+            Err(CastError::Layout {
+                align: align_of::<P>(),
+                actual: align_of::<A>(),
+            })
+        }
+    }
+
+    /// Views the buffer as a slice of POD values.
+    ///
+    /// Fails if `P` is more strictly aligned than `A` or does not perfectly fit.
+    #[inline]
+    pub fn as_pod_slice<P: Pod>(&self) -> Result<&[P], CastError> {
+        Self::check_align::<P>()?;
+        u8::cast_slice(self)
+    }
+
+    /// Views the buffer as a mutable slice of POD values.
+    ///
+    /// Fails if `P` is more strictly aligned than `A` or does not perfectly fit.
+    #[inline]
+    pub fn as_pod_slice_mut<P: NoPadding>(&mut self) -> Result<&mut [P], CastError> {
+        Self::check_align::<P>()?;
+        u8::cast_slice_mut(self)
+    }
+
+    /// Borrows the zeroed storage past the end of the buffer.
+    #[inline]
+    pub(crate) fn spare_mut(&mut self) -> &mut [u8] {
+        let len = self.len;
+        &mut A::slice_as_bytes_mut(&mut self.storage)[len..]
+    }
+
+    /// Extends the buffer over `len` bytes of its spare storage.
+    #[inline]
+    #[cfg(feature = "std")]
+    pub(crate) fn advance(&mut self, len: usize) {
+        assert!(len <= self.spare_mut().len());
+        self.len += len;
+    }
+}
+
+impl<A: NoPadding> Deref for AlignedBuf<A> {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        unsafe { from_raw_parts(self.storage.as_ptr() as *const u8, self.len) }
+    }
+}
+
+impl<A: NoPadding> DerefMut for AlignedBuf<A> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [u8] {
+        unsafe { from_raw_parts_mut(self.storage.as_mut_ptr() as *mut u8, self.len) }
+    }
+}
+
+impl<A: NoPadding> Default for AlignedBuf<A> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: NoPadding> Clone for AlignedBuf<A> {
+    #[inline]
+    fn clone(&self) -> Self {
+        let mut buf = Self::with_capacity(self.len);
+        buf.extend_from_slice(self);
+        buf
+    }
+}
+
+impl<A: NoPadding> fmt::Debug for AlignedBuf<A> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<A: NoPadding> io::Write for AlignedBuf<A> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+use core::mem::size_of;
+use core::num::{NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize};
+use core::num::{NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize};
+use core::{fmt, ptr};
+#[cfg(feature = "std")]
+use std::error;
+use pod::{Pod, is_aligned_for_slice};
+
+/// An error describing why a byte slice could not be decoded as a
+/// `CheckedPod` type.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CheckedError {
+    /// The slice is not the same size as the type.
+    Size,
+    /// The slice is not aligned for the type.
+    Alignment,
+    /// The bytes are not a valid bit pattern for the type.
+    Invalid,
+}
+
+impl fmt::Display for CheckedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            CheckedError::Size => "slice is not the same size as the type",
+            CheckedError::Alignment => "slice is misaligned for the type",
+            CheckedError::Invalid => "invalid bit pattern for the type",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for CheckedError { }
+
+/// A trait for types that can be decoded from bytes once they have been
+/// validated, such as `bool`, `char` and fieldless enums.
+///
+/// All `Pod` types are trivially `CheckedPod`. Use `#[derive(CheckedPod)]` for
+/// fieldless enums and structs composed of `CheckedPod` fields.
+///
+/// # Safety
+///
+/// `validate` must only succeed for slices of `size_of::<Self>()` bytes that
+/// hold a valid instance of the type.
+pub unsafe trait CheckedPod: Sized {
+    /// Checks whether `bytes` holds a valid instance of the type.
+    fn validate(bytes: &[u8]) -> Result<(), CheckedError>;
+
+    /// Safely creates a value from a potentially unaligned slice after
+    /// validating its contents.
+    #[inline]
+    fn from_bytes_checked(bytes: &[u8]) -> Result<Self, CheckedError> {
+        Self::validate(bytes).map(|_| unsafe {
+            ptr::read_unaligned(bytes.as_ptr() as *const Self)
+        })
+    }
+
+    /// Borrows a value from a byte slice after validating its contents.
+    #[inline]
+    fn ref_from_bytes_checked(bytes: &[u8]) -> Result<&Self, CheckedError> {
+        if !is_aligned_for_slice::<Self, _>(bytes) {
+            return Err(CheckedError::Alignment)
+        }
+
+        Self::validate(bytes).map(|_| unsafe {
+            &*(bytes.as_ptr() as *const Self)
+        })
+    }
+}
+
+#[inline]
+fn check_size<P>(bytes: &[u8]) -> Result<(), CheckedError> {
+    if bytes.len() == size_of::<P>() {
+        Ok(())
+    } else {
+        Err(CheckedError::Size)
+    }
+}
+
+unsafe impl<P: Pod> CheckedPod for P {
+    #[inline]
+    fn validate(bytes: &[u8]) -> Result<(), CheckedError> {
+        check_size::<P>(bytes)
+    }
+}
+
+unsafe impl CheckedPod for bool {
+    #[inline]
+    fn validate(bytes: &[u8]) -> Result<(), CheckedError> {
+        check_size::<bool>(bytes).and_then(|_| match bytes[0] {
+            0 | 1 => Ok(()),
+            _ => Err(CheckedError::Invalid),
+        })
+    }
+}
+
+unsafe impl CheckedPod for char {
+    #[inline]
+    fn validate(bytes: &[u8]) -> Result<(), CheckedError> {
+        let value = u32::from_bytes(bytes).ok_or(CheckedError::Size)?;
+        ::core::char::from_u32(value).map(|_| ()).ok_or(CheckedError::Invalid)
+    }
+}
+
+macro_rules! checked_nonzero {
+    ($($x:ty),*) => {
+        $(
+            unsafe impl CheckedPod for Option<$x> {
+                #[inline]
+                fn validate(bytes: &[u8]) -> Result<(), CheckedError> {
+                    check_size::<Option<$x>>(bytes)
+                }
+            }
+        )*
+    };
+}
+
+checked_nonzero! {
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+}
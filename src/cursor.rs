@@ -0,0 +1,286 @@
+use core::mem::{self, align_of, size_of};
+use core::slice::{from_raw_parts, from_raw_parts_mut};
+use pod::{Pod, NoPadding};
+use error::CastError;
+
+/// Returns the number of bytes needed to advance `ptr` to an alignment of `align`.
+#[inline]
+fn padding_for(ptr: *const u8, align: usize) -> usize {
+    (align - ptr as usize % align) % align
+}
+
+/// Returns the size in bytes of `len` elements of `P`, saturating on overflow.
+#[inline]
+fn size_of_len<P>(len: usize) -> usize {
+    size_of::<P>().saturating_mul(len)
+}
+
+/// A cursor that parses POD values out of a byte slice without copying.
+///
+/// Every method leaves the cursor untouched when it fails.
+#[derive(Debug, Clone)]
+pub struct PodCursor<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> PodCursor<'a> {
+    /// Creates a cursor positioned at the start of `bytes`.
+    #[inline]
+    pub fn new(bytes: &'a [u8]) -> Self {
+        PodCursor {
+            bytes,
+            pos: 0,
+        }
+    }
+
+    /// Returns the number of bytes consumed so far.
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns the bytes that have not been consumed yet.
+    #[inline]
+    pub fn remaining(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    #[inline]
+    fn take(&mut self, len: usize) -> Result<&'a [u8], CastError> {
+        CastError::check_min_size(len, self.bytes.len())?;
+        let (head, tail) = self.bytes.split_at(len);
+        self.bytes = tail;
+        self.pos += len;
+        Ok(head)
+    }
+
+    /// Advances the cursor by `len` bytes.
+    #[inline]
+    pub fn skip(&mut self, len: usize) -> Result<(), CastError> {
+        self.take(len).map(|_| ())
+    }
+
+    /// Advances the cursor to the next address aligned for `P`.
+    #[inline]
+    pub fn align_to<P: Pod>(&mut self) -> Result<(), CastError> {
+        self.skip(padding_for(self.bytes.as_ptr(), align_of::<P>()))
+    }
+
+    /// Borrows a POD value from the cursor.
+    ///
+    /// Fails if the remaining bytes are misaligned for `P` or too short.
+    #[inline]
+    pub fn ref_pod<P: Pod>(&mut self) -> Result<&'a P, CastError> {
+        CastError::check_min_size(size_of::<P>(), self.bytes.len())?;
+        CastError::check_align::<P, _>(self.bytes.as_ptr())?;
+        self.take(size_of::<P>()).map(|bytes| unsafe { &*(bytes.as_ptr() as *const P) })
+    }
+
+    /// Copies a potentially unaligned POD value out of the cursor.
+    ///
+    /// Fails if the remaining bytes are too short.
+    #[inline]
+    pub fn read_pod<P: Pod>(&mut self) -> Result<P, CastError> {
+        self.take(size_of::<P>()).map(|bytes| unsafe { Pod::from_ptr(bytes.as_ptr()) })
+    }
+
+    /// Borrows a slice of `len` POD values from the cursor.
+    ///
+    /// Fails if the remaining bytes are misaligned for `P` or too short.
+    #[inline]
+    pub fn ref_pod_slice<P: Pod>(&mut self, len: usize) -> Result<&'a [P], CastError> {
+        CastError::check_min_size(size_of_len::<P>(len), self.bytes.len())?;
+        CastError::check_align::<P, _>(self.bytes.as_ptr())?;
+        self.take(size_of_len::<P>(len)).map(|bytes| unsafe { from_raw_parts(bytes.as_ptr() as *const P, len) })
+    }
+}
+
+/// A cursor that parses and patches POD values in a mutable byte slice.
+///
+/// Every method leaves the cursor untouched when it fails.
+#[derive(Debug)]
+pub struct PodCursorMut<'a> {
+    bytes: &'a mut [u8],
+    pos: usize,
+}
+
+impl<'a> PodCursorMut<'a> {
+    /// Creates a cursor positioned at the start of `bytes`.
+    #[inline]
+    pub fn new(bytes: &'a mut [u8]) -> Self {
+        PodCursorMut {
+            bytes,
+            pos: 0,
+        }
+    }
+
+    /// Returns the number of bytes consumed so far.
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns the bytes that have not been consumed yet.
+    #[inline]
+    pub fn remaining(&mut self) -> &mut [u8] {
+        self.bytes
+    }
+
+    /// Consumes the cursor, returning the bytes that have not been consumed yet.
+    #[inline]
+    pub fn into_remaining(self) -> &'a mut [u8] {
+        self.bytes
+    }
+
+    #[inline]
+    fn take(&mut self, len: usize) -> Result<&'a mut [u8], CastError> {
+        CastError::check_min_size(len, self.bytes.len())?;
+        let (head, tail) = mem::take(&mut self.bytes).split_at_mut(len);
+        self.bytes = tail;
+        self.pos += len;
+        Ok(head)
+    }
+
+    /// Advances the cursor by `len` bytes.
+    #[inline]
+    pub fn skip(&mut self, len: usize) -> Result<(), CastError> {
+        self.take(len).map(|_| ())
+    }
+
+    /// Advances the cursor to the next address aligned for `P`.
+    #[inline]
+    pub fn align_to<P: Pod>(&mut self) -> Result<(), CastError> {
+        self.skip(padding_for(self.bytes.as_ptr(), align_of::<P>()))
+    }
+
+    /// Mutably borrows a POD value from the cursor.
+    ///
+    /// Fails if the remaining bytes are misaligned for `P` or too short.
+    #[inline]
+    pub fn ref_pod_mut<P: NoPadding>(&mut self) -> Result<&'a mut P, CastError> {
+        let () = P::__ASSERT_NO_PADDING;
+        CastError::check_min_size(size_of::<P>(), self.bytes.len())?;
+        CastError::check_align::<P, _>(self.bytes.as_ptr())?;
+        self.take(size_of::<P>()).map(|bytes| unsafe { &mut *(bytes.as_mut_ptr() as *mut P) })
+    }
+
+    /// Copies a potentially unaligned POD value out of the cursor.
+    ///
+    /// Fails if the remaining bytes are too short.
+    #[inline]
+    pub fn read_pod<P: Pod>(&mut self) -> Result<P, CastError> {
+        self.take(size_of::<P>()).map(|bytes| unsafe { Pod::from_ptr(bytes.as_ptr()) })
+    }
+
+    /// Mutably borrows a slice of `len` POD values from the cursor.
+    ///
+    /// Fails if the remaining bytes are misaligned for `P` or too short.
+    #[inline]
+    pub fn ref_pod_slice_mut<P: NoPadding>(&mut self, len: usize) -> Result<&'a mut [P], CastError> {
+        let () = P::__ASSERT_NO_PADDING;
+        CastError::check_min_size(size_of_len::<P>(len), self.bytes.len())?;
+        CastError::check_align::<P, _>(self.bytes.as_ptr())?;
+        self.take(size_of_len::<P>(len)).map(|bytes| unsafe { from_raw_parts_mut(bytes.as_mut_ptr() as *mut P, len) })
+    }
+
+    /// Overwrites the bytes at the cursor with a POD value.
+    ///
+    /// Fails if the remaining bytes are too short.
+    #[inline]
+    pub fn write_pod<P: NoPadding>(&mut self, data: &P) -> Result<(), CastError> {
+        self.take(size_of::<P>()).map(|bytes| bytes.copy_from_slice(data.as_bytes()))
+    }
+}
+
+/// A writer that serializes POD values into a byte slice.
+///
+/// Every method leaves the writer untouched when the value does not fit.
+#[derive(Debug)]
+pub struct PodSliceWriter<'a> {
+    bytes: &'a mut [u8],
+    pos: usize,
+}
+
+impl<'a> PodSliceWriter<'a> {
+    /// Creates a writer positioned at the start of `bytes`.
+    #[inline]
+    pub fn new(bytes: &'a mut [u8]) -> Self {
+        PodSliceWriter {
+            bytes,
+            pos: 0,
+        }
+    }
+
+    /// Returns the number of bytes written so far.
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns the number of bytes that can still be written.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.bytes.len() - self.pos
+    }
+
+    /// Returns the bytes written so far.
+    #[inline]
+    pub fn written(&self) -> &[u8] {
+        &self.bytes[..self.pos]
+    }
+
+    /// Consumes the writer, returning the bytes written so far.
+    #[inline]
+    pub fn into_written(self) -> &'a mut [u8] {
+        &mut self.bytes[..self.pos]
+    }
+
+    #[inline]
+    fn take(&mut self, len: usize) -> Result<&mut [u8], CastError> {
+        CastError::check_min_size(len, self.remaining())?;
+        let start = self.pos;
+        self.pos += len;
+        Ok(&mut self.bytes[start..self.pos])
+    }
+
+    /// Writes a POD value.
+    #[inline]
+    pub fn write_pod<P: NoPadding>(&mut self, data: &P) -> Result<(), CastError> {
+        self.write_bytes(data.as_bytes())
+    }
+
+    /// Writes a slice of POD values.
+    #[inline]
+    pub fn write_pod_slice<P: NoPadding>(&mut self, data: &[P]) -> Result<(), CastError> {
+        self.write_bytes(P::slice_as_bytes(data))
+    }
+
+    /// Writes raw bytes.
+    #[inline]
+    pub fn write_bytes(&mut self, data: &[u8]) -> Result<(), CastError> {
+        self.take(data.len()).map(|bytes| bytes.copy_from_slice(data))
+    }
+
+    /// Writes zeroes up to the next address aligned for `P`.
+    #[inline]
+    pub fn pad_to_align<P: Pod>(&mut self) -> Result<(), CastError> {
+        let len = padding_for(self.bytes[self.pos..].as_ptr(), align_of::<P>());
+        self.take(len).map(|bytes| bytes.fill(0))
+    }
+
+    /// Reserves space for a zeroed POD value and borrows it for in-place
+    /// construction.
+    ///
+    /// Fails if the position is misaligned for `P`; use `pad_to_align` first.
+    #[inline]
+    pub fn reserve<P: NoPadding>(&mut self) -> Result<&mut P, CastError> {
+        let () = P::__ASSERT_NO_PADDING;
+        CastError::check_min_size(size_of::<P>(), self.remaining())?;
+        CastError::check_align::<P, _>(self.bytes[self.pos..].as_ptr())?;
+        self.take(size_of::<P>()).map(|bytes| {
+            bytes.fill(0);
+            unsafe { &mut *(bytes.as_mut_ptr() as *mut P) }
+        })
+    }
+}
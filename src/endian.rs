@@ -0,0 +1,297 @@
+//! Integer and floating point types with an explicit byte order.
+//!
+//! `Le<T>` and `Be<T>` are stored as raw bytes with an alignment of `1`, so
+//! structs built from them have the same representation on every target and
+//! can be placed anywhere in a packed header.
+//!
+//! Structs with native fields can instead be converted as a whole using
+//! `EndianConvert`.
+
+use core::marker::PhantomData;
+use core::hash::{Hash, Hasher};
+use core::fmt;
+use pod::{Pod, Zeroable, NoPadding};
+
+/// A trait for converting a value between native and a specific byte order.
+///
+/// Use `#[derive(EndianConvert)]` to convert a struct field by field.
+pub trait EndianConvert: Sized {
+    /// Reverses the byte order of the value.
+    fn swap_bytes(self) -> Self;
+
+    /// Converts the value from native to little endian byte order.
+    #[inline]
+    fn to_le(self) -> Self {
+        if cfg!(target_endian = "little") {
+            self
+        } else {
+            self.swap_bytes()
+        }
+    }
+
+    /// Converts the value from native to big endian byte order.
+    #[inline]
+    fn to_be(self) -> Self {
+        if cfg!(target_endian = "big") {
+            self
+        } else {
+            self.swap_bytes()
+        }
+    }
+
+    /// Converts a value from little endian to native byte order.
+    #[inline]
+    fn from_le(value: Self) -> Self {
+        value.to_le()
+    }
+
+    /// Converts a value from big endian to native byte order.
+    #[inline]
+    fn from_be(value: Self) -> Self {
+        value.to_be()
+    }
+}
+
+/// A primitive type that can be stored with an explicit byte order.
+///
+/// # Safety
+///
+/// `Bytes` must be a byte array with the same size as the type.
+pub unsafe trait EndianPrimitive: Pod + Copy {
+    /// The byte array representation of the type.
+    type Bytes: NoPadding + Copy;
+
+    /// Creates a value from its little endian representation.
+    fn from_le_bytes(bytes: Self::Bytes) -> Self;
+
+    /// Creates a value from its big endian representation.
+    fn from_be_bytes(bytes: Self::Bytes) -> Self;
+
+    /// Returns the little endian representation of the value.
+    fn to_le_bytes(self) -> Self::Bytes;
+
+    /// Returns the big endian representation of the value.
+    fn to_be_bytes(self) -> Self::Bytes;
+}
+
+macro_rules! endian_type {
+    ($name:ident, $from_bytes:ident, $to_bytes:ident, $order:expr) => {
+        #[doc = concat!("A ", $order, " endian value with an alignment of `1`.")]
+        #[repr(transparent)]
+        pub struct $name<T: EndianPrimitive> {
+            bytes: T::Bytes,
+            _marker: PhantomData<T>,
+        }
+
+        impl<T: EndianPrimitive> $name<T> {
+            #[doc = concat!("Stores a native value in ", $order, " endian byte order.")]
+            #[inline]
+            pub fn new(value: T) -> Self {
+                $name {
+                    bytes: value.$to_bytes(),
+                    _marker: PhantomData,
+                }
+            }
+
+            /// Returns the value in native byte order.
+            #[inline]
+            pub fn get(self) -> T {
+                T::$from_bytes(self.bytes)
+            }
+
+            /// Replaces the stored value.
+            #[inline]
+            pub fn set(&mut self, value: T) {
+                self.bytes = value.$to_bytes();
+            }
+        }
+
+        unsafe impl<T: EndianPrimitive> Zeroable for $name<T> { }
+        unsafe impl<T: EndianPrimitive> Pod for $name<T> { }
+        unsafe impl<T: EndianPrimitive> NoPadding for $name<T> { }
+
+        /// The byte order is already explicit, so conversions leave the value unchanged.
+        impl<T: EndianPrimitive> EndianConvert for $name<T> {
+            #[inline]
+            fn swap_bytes(self) -> Self {
+                self
+            }
+
+            #[inline]
+            fn to_le(self) -> Self {
+                self
+            }
+
+            #[inline]
+            fn to_be(self) -> Self {
+                self
+            }
+        }
+
+        impl<T: EndianPrimitive> Clone for $name<T> {
+            #[inline]
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<T: EndianPrimitive> Copy for $name<T> { }
+
+        impl<T: EndianPrimitive> Default for $name<T> {
+            #[inline]
+            fn default() -> Self {
+                Self::zeroed()
+            }
+        }
+
+        impl<T: EndianPrimitive> From<T> for $name<T> {
+            #[inline]
+            fn from(value: T) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl<T: EndianPrimitive + PartialEq> PartialEq for $name<T> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.get() == other.get()
+            }
+        }
+
+        impl<T: EndianPrimitive + Eq> Eq for $name<T> { }
+
+        impl<T: EndianPrimitive + Hash> Hash for $name<T> {
+            #[inline]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.get().hash(state)
+            }
+        }
+
+        impl<T: EndianPrimitive + fmt::Debug> fmt::Debug for $name<T> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_tuple(stringify!($name)).field(&self.get()).finish()
+            }
+        }
+    };
+}
+
+endian_type!(Le, from_le_bytes, to_le_bytes, "little");
+endian_type!(Be, from_be_bytes, to_be_bytes, "big");
+
+macro_rules! endian_primitive {
+    ($($ty:ident: $size:expr, $le:ident, $be:ident;)*) => {
+        $(
+            unsafe impl EndianPrimitive for $ty {
+                type Bytes = [u8; $size];
+
+                #[inline]
+                fn from_le_bytes(bytes: Self::Bytes) -> Self {
+                    $ty::from_le_bytes(bytes)
+                }
+
+                #[inline]
+                fn from_be_bytes(bytes: Self::Bytes) -> Self {
+                    $ty::from_be_bytes(bytes)
+                }
+
+                #[inline]
+                fn to_le_bytes(self) -> Self::Bytes {
+                    self.to_le_bytes()
+                }
+
+                #[inline]
+                fn to_be_bytes(self) -> Self::Bytes {
+                    self.to_be_bytes()
+                }
+            }
+
+            impl From<Le<$ty>> for $ty {
+                #[inline]
+                fn from(value: Le<$ty>) -> Self {
+                    value.get()
+                }
+            }
+
+            impl From<Be<$ty>> for $ty {
+                #[inline]
+                fn from(value: Be<$ty>) -> Self {
+                    value.get()
+                }
+            }
+
+            #[doc = concat!("A little endian `", stringify!($ty), "`.")]
+            pub type $le = Le<$ty>;
+
+            #[doc = concat!("A big endian `", stringify!($ty), "`.")]
+            pub type $be = Be<$ty>;
+        )*
+    };
+}
+
+endian_primitive! {
+    u16: 2, U16Le, U16Be;
+    u32: 4, U32Le, U32Be;
+    u64: 8, U64Le, U64Be;
+    u128: 16, U128Le, U128Be;
+    i16: 2, I16Le, I16Be;
+    i32: 4, I32Le, I32Be;
+    i64: 8, I64Le, I64Be;
+    i128: 16, I128Le, I128Be;
+    f32: 4, F32Le, F32Be;
+    f64: 8, F64Le, F64Be;
+}
+
+macro_rules! endian_convert {
+    ($($ty:ty),*) => {
+        $(
+            impl EndianConvert for $ty {
+                #[inline]
+                fn swap_bytes(self) -> Self {
+                    self.swap_bytes()
+                }
+            }
+        )*
+    };
+}
+
+endian_convert! { i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize }
+
+impl EndianConvert for f32 {
+    #[inline]
+    fn swap_bytes(self) -> Self {
+        f32::from_bits(self.to_bits().swap_bytes())
+    }
+}
+
+impl EndianConvert for f64 {
+    #[inline]
+    fn swap_bytes(self) -> Self {
+        f64::from_bits(self.to_bits().swap_bytes())
+    }
+}
+
+impl EndianConvert for () {
+    #[inline]
+    fn swap_bytes(self) -> Self { }
+}
+
+impl<T> EndianConvert for *const T {
+    #[inline]
+    fn swap_bytes(self) -> Self {
+        (self as usize).swap_bytes() as *const T
+    }
+}
+
+impl<T> EndianConvert for *mut T {
+    #[inline]
+    fn swap_bytes(self) -> Self {
+        (self as usize).swap_bytes() as *mut T
+    }
+}
+
+impl<T: EndianConvert> EndianConvert for (T,) {
+    #[inline]
+    fn swap_bytes(self) -> Self {
+        (self.0.swap_bytes(),)
+    }
+}
@@ -0,0 +1,128 @@
+use core::mem::align_of;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error;
+use pod::is_aligned_for;
+
+/// An error describing why a POD conversion failed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CastError {
+    /// The source is not the size required by the target type.
+    Size {
+        /// The size in bytes required by the target type.
+        expected: usize,
+        /// The size in bytes of the source.
+        actual: usize,
+    },
+    /// The source is not a multiple of the size of the target element type,
+    /// or the target element type is zero-sized.
+    Remainder {
+        /// The size in bytes of the target element type.
+        size: usize,
+        /// The size in bytes of the source.
+        actual: usize,
+    },
+    /// The source address is not aligned for the target type.
+    Alignment {
+        /// The alignment required by the target type.
+        align: usize,
+        /// The offset in bytes of the source address from that alignment.
+        offset: usize,
+    },
+    /// An allocation does not have the alignment required by the target type.
+    /// Owned allocations cannot change alignment at all, as they would be
+    /// freed with the alignment of the target type rather than the one they
+    /// were allocated with.
+    Layout {
+        /// The alignment required by the target type.
+        align: usize,
+        /// The alignment the source was allocated with.
+        actual: usize,
+    },
+}
+
+impl CastError {
+    /// Fails unless `actual` is exactly `expected` bytes.
+    #[inline]
+    pub(crate) fn check_size(expected: usize, actual: usize) -> Result<(), CastError> {
+        if expected == actual {
+            Ok(())
+        } else {
+            Err(CastError::Size {
+                expected,
+                actual,
+            })
+        }
+    }
+
+    /// Fails unless `actual` is at least `expected` bytes.
+    #[inline]
+    pub(crate) fn check_min_size(expected: usize, actual: usize) -> Result<(), CastError> {
+        if expected <= actual {
+            Ok(())
+        } else {
+            Err(CastError::Size {
+                expected,
+                actual,
+            })
+        }
+    }
+
+    /// Returns the number of `size` elements that fit perfectly in `actual` bytes.
+    #[inline]
+    pub(crate) fn check_multiple(size: usize, actual: usize) -> Result<usize, CastError> {
+        if size != 0 && actual % size == 0 {
+            Ok(actual / size)
+        } else {
+            Err(CastError::Remainder {
+                size,
+                actual,
+            })
+        }
+    }
+
+    /// Fails unless an allocation of `U` can be freed as `T`, given equal sizes.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    pub(crate) fn check_layout<T, U>() -> Result<(), CastError> {
+        if align_of::<T>() == align_of::<U>() {
+            Ok(())
+        } else {
+            Err(CastError::Layout {
+                align: align_of::<T>(),
+                actual: align_of::<U>(),
+            })
+        }
+    }
+
+    /// Fails unless `ptr` is aligned for `T`.
+    #[inline]
+    pub(crate) fn check_align<T, U>(ptr: *const U) -> Result<(), CastError> {
+        if is_aligned_for::<T, U>(ptr) {
+            Ok(())
+        } else {
+            Err(CastError::Alignment {
+                align: align_of::<T>(),
+                offset: ptr as usize % align_of::<T>(),
+            })
+        }
+    }
+}
+
+impl fmt::Display for CastError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CastError::Size { expected, actual } =>
+                write!(f, "expected {} bytes, found {}", expected, actual),
+            CastError::Remainder { size, actual } =>
+                write!(f, "{} bytes cannot be divided into elements of {} bytes", actual, size),
+            CastError::Alignment { align, offset } =>
+                write!(f, "address is {} bytes past an alignment of {}", offset, align),
+            CastError::Layout { align, actual } =>
+                write!(f, "allocation has an alignment of {}, expected {}", actual, align),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl error::Error for CastError { }
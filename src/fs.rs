@@ -0,0 +1,189 @@
+use std::fs::File;
+use std::io::{self, Read, Write, Seek, SeekFrom};
+use std::mem::size_of;
+use std::sync::{Mutex, PoisonError};
+#[cfg(unix)]
+use std::os::unix::fs::FileExt;
+#[cfg(windows)]
+use std::os::windows::fs::FileExt;
+use pod::{Pod, NoPadding};
+#[cfg(any(unix, windows))]
+use pod::as_uninit_bytes_mut;
+#[cfg(any(unix, windows))]
+use io::zero_bytes;
+use io::{PodReadExt, PodWriteExt};
+
+/// Returns the offset of record `index` in an array of `P`.
+#[inline]
+fn record_offset<P: Pod>(index: u64) -> io::Result<u64> {
+    index.checked_mul(size_of::<P>() as u64)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "record offset overflows u64"))
+}
+
+/// Returns the number of whole `P` records in `len` bytes.
+#[inline]
+fn record_count<P: Pod>(len: u64) -> u64 {
+    len.checked_div(size_of::<P>() as u64).unwrap_or(0)
+}
+
+/// Reads a `Pod` struct at `offset` by seeking the stream first.
+#[inline]
+fn seek_read_pod<R: Read + Seek, P: Pod>(r: &mut R, offset: u64) -> io::Result<P> {
+    r.seek(SeekFrom::Start(offset))?;
+    r.read_pod()
+}
+
+/// Writes a `Pod` struct at `offset` by seeking the stream first.
+#[inline]
+fn seek_write_pod<W: Write + Seek, P: NoPadding>(w: &mut W, offset: u64, data: &P) -> io::Result<()> {
+    w.seek(SeekFrom::Start(offset))?;
+    w.write_pod(data)
+}
+
+/// Fills `buf` from `offset` in the file, like `read_exact_at` on Unix.
+#[cfg(windows)]
+fn read_exact_at(file: &File, mut buf: &mut [u8], mut offset: u64) -> io::Result<()> {
+    while !buf.is_empty() {
+        match file.seek_read(buf, offset) {
+            Ok(0) => return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "failed to fill whole buffer")),
+            Ok(read) => {
+                buf = &mut buf[read..];
+                offset += read as u64;
+            },
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => (),
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes all of `buf` at `offset` in the file, like `write_all_at` on Unix.
+#[cfg(windows)]
+fn write_all_at(file: &File, mut buf: &[u8], mut offset: u64) -> io::Result<()> {
+    while !buf.is_empty() {
+        match file.seek_write(buf, offset) {
+            Ok(0) => return Err(io::Error::new(io::ErrorKind::WriteZero, "failed to write whole buffer")),
+            Ok(written) => {
+                buf = &buf[written..];
+                offset += written as u64;
+            },
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => (),
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(())
+}
+
+/// An extension trait for reading `Pod` types at an offset in a file,
+/// without going through the file cursor.
+///
+/// On Unix and Windows the reads are positional and may be shared between
+/// threads, although on Windows they also move the file cursor. On other
+/// platforms they fall back to seeking, which is not atomic, so a `File`
+/// must not be read or written from several threads at once there.
+///
+/// Any other `Read + Seek` stream can be used through a `Mutex`, which is
+/// held across the seek and the read. This moves the stream position.
+pub trait PodReadAtExt {
+    /// Reads a `Pod` struct at `offset` bytes into the file. Behaves like
+    /// `read_exact`.
+    fn read_pod_at<P: Pod>(&self, offset: u64) -> io::Result<P>;
+
+    /// Returns the number of whole `Pod` records in the file.
+    fn record_count<P: Pod>(&self) -> io::Result<u64>;
+
+    /// Reads record `index` from a file made up of an array of `Pod` structs.
+    #[inline]
+    fn read_record<P: Pod>(&self, index: u64) -> io::Result<P> {
+        self.read_pod_at(record_offset::<P>(index)?)
+    }
+}
+
+/// An extension trait for writing `Pod` types at an offset in a file,
+/// without going through the file cursor.
+///
+/// See `PodReadAtExt` for the platform differences.
+pub trait PodWriteAtExt {
+    /// Writes a `Pod` struct at `offset` bytes into the file. Behaves like
+    /// `write_all`.
+    fn write_pod_at<P: NoPadding>(&self, offset: u64, data: &P) -> io::Result<()>;
+
+    /// Writes record `index` in a file made up of an array of `Pod` structs.
+    #[inline]
+    fn write_record<P: NoPadding>(&self, index: u64, data: &P) -> io::Result<()> {
+        self.write_pod_at(record_offset::<P>(index)?, data)
+    }
+}
+
+impl PodReadAtExt for File {
+    #[inline]
+    #[cfg(unix)]
+    fn read_pod_at<P: Pod>(&self, offset: u64) -> io::Result<P> {
+        let mut data = P::uninit();
+
+        self.read_exact_at(zero_bytes(as_uninit_bytes_mut(&mut data)), offset).map(|_| unsafe { data.assume_init() })
+    }
+
+    #[inline]
+    #[cfg(windows)]
+    fn read_pod_at<P: Pod>(&self, offset: u64) -> io::Result<P> {
+        let mut data = P::uninit();
+
+        read_exact_at(self, zero_bytes(as_uninit_bytes_mut(&mut data)), offset).map(|_| unsafe { data.assume_init() })
+    }
+
+    #[inline]
+    #[cfg(not(any(unix, windows)))]
+    fn read_pod_at<P: Pod>(&self, offset: u64) -> io::Result<P> {
+        seek_read_pod(&mut &*self, offset)
+    }
+
+    #[inline]
+    fn record_count<P: Pod>(&self) -> io::Result<u64> {
+        self.metadata().map(|m| record_count::<P>(m.len()))
+    }
+}
+
+impl<R: Read + Seek> PodReadAtExt for Mutex<R> {
+    #[inline]
+    fn read_pod_at<P: Pod>(&self, offset: u64) -> io::Result<P> {
+        seek_read_pod(&mut *self.lock().unwrap_or_else(PoisonError::into_inner), offset)
+    }
+
+    fn record_count<P: Pod>(&self) -> io::Result<u64> {
+        let mut stream = self.lock().unwrap_or_else(PoisonError::into_inner);
+        let pos = stream.stream_position()?;
+        let len = stream.seek(SeekFrom::End(0))?;
+        stream.seek(SeekFrom::Start(pos))?;
+        Ok(record_count::<P>(len))
+    }
+}
+
+impl PodWriteAtExt for File {
+    #[inline]
+    #[cfg(unix)]
+    fn write_pod_at<P: NoPadding>(&self, offset: u64, data: &P) -> io::Result<()> {
+        self.write_all_at(data.as_bytes(), offset)
+    }
+
+    #[inline]
+    #[cfg(windows)]
+    fn write_pod_at<P: NoPadding>(&self, offset: u64, data: &P) -> io::Result<()> {
+        write_all_at(self, data.as_bytes(), offset)
+    }
+
+    #[inline]
+    #[cfg(not(any(unix, windows)))]
+    fn write_pod_at<P: NoPadding>(&self, offset: u64, data: &P) -> io::Result<()> {
+        seek_write_pod(&mut &*self, offset, data)
+    }
+}
+
+impl<W: Write + Seek> PodWriteAtExt for Mutex<W> {
+    #[inline]
+    fn write_pod_at<P: NoPadding>(&self, offset: u64, data: &P) -> io::Result<()> {
+        seek_write_pod(&mut *self.lock().unwrap_or_else(PoisonError::into_inner), offset, data)
+    }
+}
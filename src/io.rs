@@ -1,45 +1,569 @@
-use std::io;
-use pod::Pod;
-
-#[cfg(feature = "uninitialized")]
-use uninitialized::uninitialized;
-#[cfg(not(feature = "uninitialized"))]
-use std::mem::zeroed as uninitialized;
-
+use std::{fmt, error, io};
+use std::convert::TryInto;
 #[cfg(feature = "read_exact")]
+use std::marker::PhantomData;
+use std::mem::{size_of, size_of_val, MaybeUninit};
+use std::slice::from_raw_parts_mut;
+use std::iter;
+use std::vec::Vec;
+use std::boxed::Box;
+use std::ptr;
+#[cfg(feature = "nightly")]
+use std::io::BorrowedBuf;
+use pod::{Pod, NoPadding, AsPodBytes, AsPodBytesMut, as_uninit_bytes_mut, is_aligned_for_slice};
+use checked::CheckedPod;
+use buf::AlignedBuf;
+use endian::EndianConvert;
+
+#[cfg(all(feature = "read_exact", not(feature = "nightly")))]
 use read_exact::ReadExactExt;
 
+/// Assumes that a fully written buffer is initialized.
+#[inline]
+unsafe fn assume_init_bytes(buf: &mut [MaybeUninit<u8>]) -> &mut [u8] {
+    &mut *(buf as *mut [MaybeUninit<u8>] as *mut [u8])
+}
+
+/// Zeroes a buffer so that it may be passed to `Read::read`, which is allowed
+/// to inspect it.
+#[inline]
+pub(crate) fn zero_bytes(buf: &mut [MaybeUninit<u8>]) -> &mut [u8] {
+    unsafe {
+        ptr::write_bytes(buf.as_mut_ptr(), 0, buf.len());
+        assume_init_bytes(buf)
+    }
+}
+
+/// Views a slice of potentially uninitialized values as bytes.
+#[inline]
+fn uninit_slice_bytes_mut<T>(s: &mut [MaybeUninit<T>]) -> &mut [MaybeUninit<u8>] {
+    unsafe { from_raw_parts_mut(s.as_mut_ptr() as *mut MaybeUninit<u8>, size_of_val(s)) }
+}
+
+/// Reads some bytes into `buf`, like `read`.
+///
+/// `init` is the number of bytes at the start of `buf` that are already
+/// initialized, and is updated so that repeated reads into the same buffer
+/// only ever initialize each byte once. This zeroes even with the `nightly`
+/// feature, as `BorrowedBuf` has no stable way to resume from a partially
+/// initialized buffer.
+#[inline]
+fn read_uninit<R: io::Read>(r: &mut R, buf: &mut [MaybeUninit<u8>], init: &mut usize) -> io::Result<usize> {
+    zero_bytes(&mut buf[*init..]);
+    *init = buf.len();
+    r.read(unsafe { assume_init_bytes(buf) })
+}
+
+/// Fills `buf` from the stream, like `read_exact`.
+#[inline]
+#[cfg(not(feature = "nightly"))]
+fn read_exact_uninit<R: io::Read>(r: &mut R, buf: &mut [MaybeUninit<u8>]) -> io::Result<()> {
+    r.read_exact(zero_bytes(buf))
+}
+
+/// Fills `buf` from the stream, like `read_exact`.
+#[inline]
+#[cfg(feature = "nightly")]
+fn read_exact_uninit<R: io::Read>(r: &mut R, buf: &mut [MaybeUninit<u8>]) -> io::Result<()> {
+    let mut buf = BorrowedBuf::from(buf);
+    r.read_buf_exact(buf.unfilled())
+}
+
+/// Fills `buf` from the stream, returning `false` if EOF was reached before
+/// anything was read.
+#[inline]
+#[cfg(all(feature = "read_exact", not(feature = "nightly")))]
+fn read_exact_or_eof_uninit<R: io::Read>(r: &mut R, buf: &mut [MaybeUninit<u8>]) -> io::Result<bool> {
+    r.read_exact_or_eof(zero_bytes(buf))
+}
+
+/// Fills `buf` from the stream, returning `false` if EOF was reached before
+/// anything was read.
+#[cfg(all(feature = "read_exact", feature = "nightly"))]
+fn read_exact_or_eof_uninit<R: io::Read>(r: &mut R, buf: &mut [MaybeUninit<u8>]) -> io::Result<bool> {
+    let mut buf = BorrowedBuf::from(buf);
+    while buf.len() < buf.capacity() {
+        let filled = buf.len();
+        match r.read_buf(buf.unfilled()) {
+            Ok(()) if buf.len() == filled => break,
+            Ok(()) => (),
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => (),
+            Err(e) => return Err(e),
+        }
+    }
+
+    match buf.len() {
+        0 if buf.capacity() > 0 => Ok(false),
+        len if len == buf.capacity() => Ok(true),
+        _ => Err(io::Error::new(io::ErrorKind::UnexpectedEof, "failed to fill whole buffer")),
+    }
+}
+
+/// The number of bytes read at a time by the limited reads, which only grow
+/// their buffer as data arrives.
+const LIMITED_CHUNK_SIZE: usize = 64 * 1024;
+
+/// The initial capacity of `read_to_end_aligned`, which then doubles as data
+/// arrives.
+const ALIGNED_INITIAL_CAPACITY: usize = 8 * 1024;
+
+/// An error returned by the limited reads when the stream requests more data
+/// than allowed, wrapped in an `io::Error` of kind `InvalidData`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct LimitExceeded {
+    /// The number of bytes requested by the stream, saturating at `usize::MAX`.
+    pub requested: usize,
+    /// The maximum number of bytes allowed.
+    pub limit: usize,
+}
+
+impl LimitExceeded {
+    #[inline]
+    fn check(requested: usize, limit: usize) -> io::Result<()> {
+        if requested <= limit {
+            Ok(())
+        } else {
+            Err(io::Error::new(io::ErrorKind::InvalidData, LimitExceeded {
+                requested,
+                limit,
+            }))
+        }
+    }
+}
+
+impl fmt::Display for LimitExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "stream requested {} bytes, exceeding the limit of {}", self.requested, self.limit)
+    }
+}
+
+impl error::Error for LimitExceeded { }
+
+/// An error returned by `PodBufReadExt` when some, but not enough, bytes are
+/// buffered to inspect a struct, wrapped in an `io::Error` of kind `Other`.
+///
+/// This happens when the struct straddles the end of the buffer, or when the
+/// stream ends with a partial struct. Either way it must be read instead.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct BufferTooShort {
+    /// The number of bytes in the buffer.
+    pub buffered: usize,
+    /// The size of the struct.
+    pub required: usize,
+}
+
+impl fmt::Display for BufferTooShort {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "only {} of {} bytes are buffered", self.buffered, self.required)
+    }
+}
+
+impl error::Error for BufferTooShort { }
+
 /// An extension trait for reading `Pod` types from `std::io::Read` data streams.
 pub trait PodReadExt {
     /// Reads a `Pod` struct from the stream. Behaves like `read_exact`, and will
     /// produce an error if EOF is encountered before the data is fully read.
     fn read_pod<P: Pod>(&mut self) -> io::Result<P>;
 
+    /// Reads a `Pod` struct from the stream directly onto the heap, so that
+    /// large types never touch the stack. Behaves like `read_exact`.
+    fn read_pod_boxed<P: Pod>(&mut self) -> io::Result<Box<P>>;
+
+    /// Reads a `Pod` struct from the stream in place. Behaves like
+    /// `read_exact`, and may leave `data` partially overwritten on error.
+    fn read_pod_into<P: Pod>(&mut self, data: &mut P) -> io::Result<()>;
+
     /// Reads a `Pod` struct from the stream, or nothing at EOF. Partial reads
     /// will result in an error.
     #[cfg(feature = "read_exact")]
     fn read_pod_or_none<P: Pod>(&mut self) -> io::Result<Option<P>>;
+
+    /// Reads a little endian `Pod` struct from the stream, converting it to
+    /// native byte order.
+    #[inline]
+    fn read_pod_le<P: Pod + EndianConvert>(&mut self) -> io::Result<P> {
+        self.read_pod().map(P::from_le)
+    }
+
+    /// Reads a big endian `Pod` struct from the stream, converting it to
+    /// native byte order.
+    #[inline]
+    fn read_pod_be<P: Pod + EndianConvert>(&mut self) -> io::Result<P> {
+        self.read_pod().map(P::from_be)
+    }
+
+    /// Reads a `CheckedPod` value from the stream, validating its contents
+    /// before returning it. Invalid data will result in an `InvalidData` error.
+    fn read_pod_checked<P: CheckedPod>(&mut self) -> io::Result<P>;
+
+    /// Fills a slice of `Pod` structs from the stream in a single transfer.
+    /// Behaves like `read_exact`.
+    fn read_pod_slice<P: Pod>(&mut self, data: &mut [P]) -> io::Result<()>;
+
+    /// Reads `count` `Pod` structs from the stream in a single transfer.
+    /// Behaves like `read_exact`.
+    fn read_pod_vec<P: Pod>(&mut self, count: usize) -> io::Result<Vec<P>>;
+
+    /// Reads `Pod` structs from the stream until EOF. A trailing partial
+    /// struct will result in an `UnexpectedEof` error.
+    fn read_to_end_pod<P: Pod>(&mut self) -> io::Result<Vec<P>>;
+
+    /// Reads `count` `Pod` structs from untrusted input, failing with
+    /// `LimitExceeded` if they would take up more than `max_bytes`. The
+    /// buffer grows as data arrives, so a short stream fails with
+    /// `UnexpectedEof` before the full count is allocated.
+    fn read_pod_vec_limited<P: Pod>(&mut self, count: usize, max_bytes: usize) -> io::Result<Vec<P>>;
+
+    /// Fills several `Pod` values of different types from the stream using
+    /// `read_vectored`. Behaves like `read_exact`.
+    fn read_pods_vectored(&mut self, data: &mut [&mut dyn AsPodBytesMut]) -> io::Result<()>;
+
+    /// Reads the rest of the stream into a buffer aligned for `A`, so that it
+    /// can be cast to any `Pod` type with an alignment no greater than `A`.
+    fn read_to_end_aligned<A: NoPadding>(&mut self) -> io::Result<AlignedBuf<A>>;
+
+    /// Reads a length prefix of type `L` in native byte order followed by that
+    /// many `Pod` structs, with the same guarantees as `read_pod_vec_limited`.
+    #[inline]
+    fn read_len_prefixed<L: Pod + TryInto<usize>, P: Pod>(&mut self, max_bytes: usize) -> io::Result<Vec<P>> {
+        let count = self.read_pod::<L>()?.try_into().unwrap_or(usize::MAX);
+        self.read_pod_vec_limited(count, max_bytes)
+    }
+
+    /// Creates an iterator that reads `Pod` structs from the stream until EOF.
+    /// A partial trailing struct yields an error, after which the iterator
+    /// ends.
+    ///
+    /// The iterator does not buffer the stream: each struct is a separate
+    /// `read_pod` call on it. Wrap unbuffered sources such as `File` in a
+    /// `BufReader`, which then serves each struct out of its own buffer.
+    ///
+    /// `size_hint` is unknown unless the iterator is created with
+    /// `PodIter::with_size_hint`, which requires the stream to be `Seek`.
+    #[inline]
+    #[cfg(feature = "read_exact")]
+    fn pod_iter<P: Pod>(self) -> PodIter<Self, P> where Self: Sized {
+        PodIter {
+            reader: self,
+            remaining: None,
+            done: false,
+            _marker: PhantomData,
+        }
+    }
 }
 
 impl<T: io::Read> PodReadExt for T {
     #[inline]
     fn read_pod<P: Pod>(&mut self) -> io::Result<P> {
-        let mut data: P = unsafe { uninitialized() };
+        let mut data = P::uninit();
 
-        self.read_exact(data.as_bytes_mut()).map(|_| data)
+        read_exact_uninit(self, as_uninit_bytes_mut(&mut data)).map(|_| unsafe { data.assume_init() })
+    }
+
+    #[inline]
+    fn read_pod_boxed<P: Pod>(&mut self) -> io::Result<Box<P>> {
+        let mut data = P::new_box_uninit();
+
+        read_exact_uninit(self, as_uninit_bytes_mut(&mut data))?;
+        Ok(unsafe { Box::from_raw(Box::into_raw(data) as *mut P) })
+    }
+
+    #[inline]
+    fn read_pod_into<P: Pod>(&mut self, data: &mut P) -> io::Result<()> {
+        let data = unsafe { &mut *(data as *mut P as *mut MaybeUninit<P>) };
+
+        read_exact_uninit(self, as_uninit_bytes_mut(data))
     }
 
     #[inline]
     #[cfg(feature = "read_exact")]
     fn read_pod_or_none<P: Pod>(&mut self) -> io::Result<Option<P>> {
-        let mut data: P = unsafe { uninitialized() };
+        let mut data = P::uninit();
 
-        self.read_exact_or_eof(data.as_bytes_mut()).map(|read| if read {
-            Some(data)
+        read_exact_or_eof_uninit(self, as_uninit_bytes_mut(&mut data)).map(|read| if read {
+            Some(unsafe { data.assume_init() })
         } else {
             None
         })
     }
+
+    #[inline]
+    fn read_pod_checked<P: CheckedPod>(&mut self) -> io::Result<P> {
+        let mut data = MaybeUninit::<P>::uninit();
+
+        {
+            let bytes = as_uninit_bytes_mut(&mut data);
+            read_exact_uninit(self, bytes)?;
+            P::validate(unsafe { assume_init_bytes(bytes) })
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        }
+
+        Ok(unsafe { data.assume_init() })
+    }
+
+    #[inline]
+    fn read_pod_slice<P: Pod>(&mut self, data: &mut [P]) -> io::Result<()> {
+        let data = unsafe { from_raw_parts_mut(data.as_mut_ptr() as *mut MaybeUninit<P>, data.len()) };
+
+        read_exact_uninit(self, uninit_slice_bytes_mut(data))
+    }
+
+    #[inline]
+    fn read_pod_vec<P: Pod>(&mut self, count: usize) -> io::Result<Vec<P>> {
+        let mut data = Vec::with_capacity(count);
+
+        read_exact_uninit(self, uninit_slice_bytes_mut(&mut data.spare_capacity_mut()[..count]))?;
+        unsafe { data.set_len(count) };
+        Ok(data)
+    }
+
+    fn read_to_end_pod<P: Pod>(&mut self) -> io::Result<Vec<P>> {
+        if size_of::<P>() == 0 {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "cannot read zero-sized structs to EOF"))
+        }
+
+        let mut data = Vec::<P>::new();
+        // Bytes of a partial struct at the start of the spare capacity
+        let mut partial = 0;
+        // Initialized bytes at the start of the spare capacity, including
+        // the partial struct
+        let mut init = 0;
+        loop {
+            if data.len() == data.capacity() {
+                data.reserve(data.capacity().max(1));
+            }
+
+            let spare = uninit_slice_bytes_mut(data.spare_capacity_mut());
+            let mut spare_init = init - partial;
+            let res = read_uninit(self, &mut spare[partial..], &mut spare_init);
+            init = partial + spare_init;
+            match res {
+                Ok(0) => break,
+                Ok(read) => {
+                    partial += read;
+                    let count = partial / size_of::<P>();
+                    let len = data.len() + count;
+                    unsafe { data.set_len(len) };
+                    partial %= size_of::<P>();
+                    init -= count * size_of::<P>();
+                },
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => (),
+                Err(e) => return Err(e),
+            }
+        }
+
+        if partial == 0 {
+            Ok(data)
+        } else {
+            Err(io::Error::new(io::ErrorKind::UnexpectedEof, "stream ended with a partial struct"))
+        }
+    }
+
+    fn read_pod_vec_limited<P: Pod>(&mut self, count: usize, max_bytes: usize) -> io::Result<Vec<P>> {
+        LimitExceeded::check(count.saturating_mul(size_of::<P>()), max_bytes)?;
+
+        let mut data = Vec::new();
+        if size_of::<P>() == 0 {
+            // Zero-sized structs take up no room in the stream or the vector
+            unsafe { data.set_len(count) };
+            return Ok(data)
+        }
+
+        let chunk = (LIMITED_CHUNK_SIZE / size_of::<P>()).max(1);
+        while data.len() < count {
+            let len = (count - data.len()).min(chunk);
+            if data.capacity() - data.len() < len {
+                // Grow geometrically, but never past the requested count
+                let capacity = data.capacity().saturating_mul(2).max(data.len() + len).min(count);
+                data.reserve_exact(capacity - data.len());
+            }
+            read_exact_uninit(self, uninit_slice_bytes_mut(&mut data.spare_capacity_mut()[..len]))?;
+            unsafe { data.set_len(data.len() + len) };
+        }
+
+        Ok(data)
+    }
+
+    fn read_to_end_aligned<A: NoPadding>(&mut self) -> io::Result<AlignedBuf<A>> {
+        let mut buf = AlignedBuf::new();
+        loop {
+            if buf.spare_mut().is_empty() {
+                let len = buf.len();
+                buf.reserve(len.max(ALIGNED_INITIAL_CAPACITY));
+            }
+
+            match self.read(buf.spare_mut()) {
+                Ok(0) => return Ok(buf),
+                Ok(read) => buf.advance(read),
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => (),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    fn read_pods_vectored(&mut self, data: &mut [&mut dyn AsPodBytesMut]) -> io::Result<()> {
+        let mut bufs = data.iter_mut().map(|d| d.as_pod_bytes_mut()).collect::<Vec<_>>();
+        let (mut index, mut offset) = (0, 0);
+        loop {
+            while index < bufs.len() && offset >= bufs[index].len() {
+                offset -= bufs[index].len();
+                index += 1;
+            }
+            if index == bufs.len() {
+                return Ok(())
+            }
+
+            let (first, rest) = bufs[index..].split_first_mut().unwrap();
+            let mut slices = iter::once(&mut first[offset..]).chain(rest.iter_mut().map(|b| &mut b[..]))
+                .map(io::IoSliceMut::new).collect::<Vec<_>>();
+            match self.read_vectored(&mut slices) {
+                Ok(0) => return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "failed to fill whole buffer")),
+                Ok(read) => offset += read,
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => (),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+/// An iterator over the `Pod` structs in a stream, created by
+/// `PodReadExt::pod_iter`.
+#[derive(Debug)]
+#[cfg(feature = "read_exact")]
+pub struct PodIter<R, P> {
+    reader: R,
+    remaining: Option<u64>,
+    done: bool,
+    _marker: PhantomData<fn() -> P>,
+}
+
+#[cfg(feature = "read_exact")]
+impl<R, P> PodIter<R, P> {
+    /// Returns the underlying stream.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+#[cfg(feature = "read_exact")]
+impl<R: io::Read + io::Seek, P> PodIter<R, P> {
+    /// Measures the rest of the stream so that `size_hint` reports the number
+    /// of structs left in it. The hint is not updated if the stream changes
+    /// length afterwards.
+    pub fn with_size_hint(mut self) -> io::Result<Self> {
+        let pos = self.reader.stream_position()?;
+        let end = self.reader.seek(io::SeekFrom::End(0))?;
+        self.reader.seek(io::SeekFrom::Start(pos))?;
+        self.remaining = Some(end.saturating_sub(pos));
+        Ok(self)
+    }
+}
+
+#[cfg(feature = "read_exact")]
+impl<R: io::Read, P: Pod> Iterator for PodIter<R, P> {
+    type Item = io::Result<P>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None
+        }
+
+        match self.reader.read_pod_or_none() {
+            Ok(Some(data)) => {
+                self.remaining = self.remaining.map(|r| r.saturating_sub(size_of::<P>() as u64));
+                Some(Ok(data))
+            },
+            Ok(None) => {
+                self.done = true;
+                None
+            },
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            },
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining {
+            _ if self.done => (0, Some(0)),
+            Some(remaining) if size_of::<P>() > 0 => {
+                let size = size_of::<P>() as u64;
+                let len = remaining / size;
+                let partial = (remaining % size != 0) as u64;
+                (len.try_into().unwrap_or(usize::MAX), (len + partial).try_into().ok())
+            },
+            _ => (0, None),
+        }
+    }
+}
+
+/// An extension trait for inspecting `Pod` types in the buffer of
+/// `std::io::BufRead` data streams without consuming them.
+///
+/// `BufRead` only refills its buffer once it is empty, so a struct that
+/// straddles the end of the buffer cannot be inspected. This is reported as
+/// a `BufferTooShort` error rather than as EOF, and the struct must be read
+/// instead.
+pub trait PodBufReadExt: io::BufRead {
+    /// Copies a `Pod` struct out of the buffer without consuming it, or
+    /// returns `None` at EOF.
+    ///
+    /// Fails with `BufferTooShort` if the struct is only partially buffered.
+    fn peek_pod<P: Pod>(&mut self) -> io::Result<Option<P>>;
+
+    /// Calls `f` with a `Pod` struct in the buffer without consuming it, or
+    /// returns `None` at EOF. The struct is borrowed directly from the buffer
+    /// when it is aligned, and copied otherwise.
+    ///
+    /// Fails with `BufferTooShort` if the struct is only partially buffered.
+    fn with_pod_ref<P: Pod, R, F: FnOnce(&P) -> R>(&mut self, f: F) -> io::Result<Option<R>>;
+
+    /// Skips over a `Pod` struct, typically one that has been inspected.
+    /// Behaves like `read_exact`.
+    fn consume_pod<P: Pod>(&mut self) -> io::Result<()>;
+}
+
+impl<T: io::BufRead> PodBufReadExt for T {
+    #[inline]
+    fn peek_pod<P: Pod>(&mut self) -> io::Result<Option<P>> {
+        self.with_pod_ref(P::copy)
+    }
+
+    #[inline]
+    fn with_pod_ref<P: Pod, R, F: FnOnce(&P) -> R>(&mut self, f: F) -> io::Result<Option<R>> {
+        let buf = self.fill_buf()?;
+        if buf.is_empty() && size_of::<P>() > 0 {
+            return Ok(None)
+        }
+        let buf = match buf.get(..size_of::<P>()) {
+            Some(buf) => buf,
+            None => return Err(io::Error::other(BufferTooShort {
+                buffered: buf.len(),
+                required: size_of::<P>(),
+            })),
+        };
+
+        Ok(Some(if is_aligned_for_slice::<P, _>(buf) {
+            f(unsafe { &*(buf.as_ptr() as *const P) })
+        } else {
+            f(&unsafe { P::from_ptr(buf.as_ptr()) })
+        }))
+    }
+
+    #[inline]
+    fn consume_pod<P: Pod>(&mut self) -> io::Result<()> {
+        if self.fill_buf()?.len() >= size_of::<P>() {
+            self.consume(size_of::<P>());
+            Ok(())
+        } else {
+            self.read_pod::<P>().map(|_| ())
+        }
+    }
 }
 
 /// An extension trait for writing `Pod` types to `std::io::Write` data streams.
@@ -47,12 +571,62 @@ pub trait PodWriteExt {
     /// Writes the memory representation of a `Pod` struct to the stream.
     /// Behaves like `write_all`, failure to write the entire structure will
     /// result in an error.
-    fn write_pod<P: Pod>(&mut self, data: &P) -> io::Result<()>;
+    ///
+    /// Only types without padding can be written, see `NoPadding`.
+    fn write_pod<P: NoPadding>(&mut self, data: &P) -> io::Result<()>;
+
+    /// Writes the memory representation of a slice of `Pod` structs to the
+    /// stream in a single transfer. Behaves like `write_all`.
+    fn write_pod_slice<P: NoPadding>(&mut self, data: &[P]) -> io::Result<()>;
+
+    /// Writes several `Pod` values of different types to the stream using
+    /// `write_vectored`. Behaves like `write_all`.
+    fn write_pods_vectored(&mut self, data: &[&dyn AsPodBytes]) -> io::Result<()>;
+
+    /// Writes a `Pod` struct to the stream in little endian byte order.
+    #[inline]
+    fn write_pod_le<P: NoPadding + EndianConvert>(&mut self, data: &P) -> io::Result<()> {
+        self.write_pod(&data.copy().to_le())
+    }
+
+    /// Writes a `Pod` struct to the stream in big endian byte order.
+    #[inline]
+    fn write_pod_be<P: NoPadding + EndianConvert>(&mut self, data: &P) -> io::Result<()> {
+        self.write_pod(&data.copy().to_be())
+    }
 }
 
 impl<T: io::Write> PodWriteExt for T {
     #[inline]
-    fn write_pod<P: Pod>(&mut self, data: &P) -> io::Result<()> {
+    fn write_pod<P: NoPadding>(&mut self, data: &P) -> io::Result<()> {
         self.write_all(data.as_bytes())
     }
+
+    #[inline]
+    fn write_pod_slice<P: NoPadding>(&mut self, data: &[P]) -> io::Result<()> {
+        self.write_all(P::slice_as_bytes(data))
+    }
+
+    fn write_pods_vectored(&mut self, data: &[&dyn AsPodBytes]) -> io::Result<()> {
+        let bufs = data.iter().map(|d| d.as_pod_bytes()).collect::<Vec<_>>();
+        let (mut index, mut offset) = (0, 0);
+        loop {
+            while index < bufs.len() && offset >= bufs[index].len() {
+                offset -= bufs[index].len();
+                index += 1;
+            }
+            if index == bufs.len() {
+                return Ok(())
+            }
+
+            let slices = iter::once(&bufs[index][offset..]).chain(bufs[index + 1..].iter().cloned())
+                .map(io::IoSlice::new).collect::<Vec<_>>();
+            match self.write_vectored(&slices) {
+                Ok(0) => return Err(io::Error::new(io::ErrorKind::WriteZero, "failed to write whole buffer")),
+                Ok(written) => offset += written,
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => (),
+                Err(e) => return Err(e),
+            }
+        }
+    }
 }
@@ -1,22 +1,71 @@
 #![deny(missing_docs)]
+#![no_std]
+#![cfg_attr(feature = "nightly", feature(read_buf, core_io_borrowed_buf))]
 
 //! Provides traits that assist with I/O and byte slice conversions involving
 //! Plain Old Data.
 //!
 //! # Safety
 //!
-//! The `nue-macros` crate can be used for safe automagic derives.
+//! Enable the `derive` feature for `#[derive(Pod)]`, which verifies the layout
+//! of a type and that its fields are all `Pod` at compile time.
+//! `#[derive(NoPadding)]` additionally allows the type to be viewed as bytes.
+//!
+//! # Features
+//!
+//! The casting API only depends on `core`. The `alloc` feature enables the
+//! `Box` and `Vec` conversions, and the default `std` feature enables the I/O
+//! extension traits. The `nightly` feature reads directly into uninitialized
+//! memory using `std::io::BorrowedBuf`, rather than zeroing it first. The
+//! `mmap` feature provides typed views of memory-mapped files.
 
-#[cfg(feature = "uninitialized")]
-extern crate uninitialized;
+#[cfg(feature = "std")]
+extern crate std;
+#[cfg(feature = "alloc")]
+extern crate alloc;
 #[cfg(feature = "read_exact")]
 extern crate read_exact;
+#[cfg(feature = "derive")]
+extern crate pod_derive;
+#[cfg(feature = "mmap")]
+extern crate memmap2;
 
 /// Re-export the `packed` crate
+#[cfg(feature = "packed")]
 pub extern crate packed;
 
 mod pod;
+mod checked;
+mod error;
+mod cursor;
+#[cfg(feature = "alloc")]
+mod buf;
+#[cfg(feature = "std")]
 mod io;
+#[cfg(feature = "std")]
+mod fs;
+pub mod endian;
+#[cfg(feature = "mmap")]
+pub mod mmap;
+
+pub use pod::{Pod, Zeroable, NoPadding, AsPodBytes, AsPodBytesMut, as_uninit_bytes_mut};
+pub use checked::{CheckedPod, CheckedError};
+pub use error::CastError;
+pub use cursor::{PodCursor, PodCursorMut, PodSliceWriter};
+#[cfg(feature = "alloc")]
+pub use buf::AlignedBuf;
+pub use endian::EndianConvert;
+#[cfg(feature = "std")]
+pub use io::{PodReadExt, PodBufReadExt, PodWriteExt, LimitExceeded, BufferTooShort};
+#[cfg(feature = "read_exact")]
+pub use io::PodIter;
+#[cfg(feature = "std")]
+pub use fs::{PodReadAtExt, PodWriteAtExt};
+#[cfg(feature = "derive")]
+pub use pod_derive::{Pod, Zeroable, NoPadding, CheckedPod, EndianConvert};
 
-pub use pod::Pod;
-pub use io::{PodReadExt, PodWriteExt};
+#[doc(hidden)]
+pub mod __derive {
+    pub use core::mem::{size_of, offset_of};
+    pub use core::result::Result;
+}
@@ -0,0 +1,229 @@
+//! Typed views of memory-mapped files, enabled by the `mmap` feature.
+//!
+//! A mapped file holds a header of type `H` followed by an array of records of
+//! type `T`, laid out exactly as they are in memory. The records must be
+//! aligned for `T` after the header, and the file length must be the header
+//! plus a whole number of records. Files without a header are mapped with
+//! `PodRecords` and `PodRecordsMut`.
+
+use std::fs::File;
+use std::io;
+use std::marker::PhantomData;
+use std::mem::{self, size_of};
+use std::ops::{Deref, DerefMut, Range};
+use std::slice::{from_raw_parts, from_raw_parts_mut};
+use memmap2::{Mmap, MmapMut};
+use pod::{Pod, NoPadding, size_of_slice};
+use error::CastError;
+
+/// Checks that `bytes` holds a header followed by whole records, returning
+/// the number of records.
+#[inline]
+fn validate<H: Pod, T: Pod>(bytes: &[u8]) -> io::Result<usize> {
+    let check = || {
+        CastError::check_min_size(size_of::<H>(), bytes.len())?;
+        let (header, records) = bytes.split_at(size_of::<H>());
+        H::cast_ref_from_bytes(header)?;
+        u8::cast_slice::<T>(records).map(|records| records.len())
+    };
+
+    check().map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// A read-only memory-mapped file of a header `H` followed by `T` records.
+#[derive(Debug)]
+pub struct PodMmap<H, T> {
+    map: Mmap,
+    len: usize,
+    _marker: PhantomData<fn() -> (H, T)>,
+}
+
+impl<H: Pod, T: Pod> PodMmap<H, T> {
+    /// Maps a file, failing with `InvalidData` if its length or alignment
+    /// does not fit the layout.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified, by this or any other process, while it
+    /// is mapped.
+    pub unsafe fn map(file: &File) -> io::Result<Self> {
+        let map = Mmap::map(file)?;
+        let len = validate::<H, T>(&map)?;
+
+        Ok(PodMmap {
+            map,
+            len,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Borrows the header.
+    #[inline]
+    pub fn header(&self) -> &H {
+        unsafe { &*(self.map.as_ptr() as *const H) }
+    }
+}
+
+impl<H: Pod, T: Pod> Deref for PodMmap<H, T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        unsafe { from_raw_parts(self.map.as_ptr().add(size_of::<H>()) as *const T, self.len) }
+    }
+}
+
+/// A read-only memory-mapped file of `T` records without a header.
+pub type PodRecords<T> = PodMmap<(), T>;
+
+/// A writable memory-mapped file of `T` records without a header.
+pub type PodRecordsMut<T> = PodMmapMut<(), T>;
+
+/// A writable memory-mapped file of a header `H` followed by `T` records.
+///
+/// Records can be appended by growing the file and remapping it. Both types
+/// must be `NoPadding`, as writing a padded value leaves uninitialized bytes
+/// in the file.
+#[derive(Debug)]
+pub struct PodMmapMut<H, T> {
+    map: MmapMut,
+    file: File,
+    len: usize,
+    _marker: PhantomData<fn() -> (H, T)>,
+}
+
+impl<H: NoPadding, T: NoPadding> PodMmapMut<H, T> {
+    /// Maps a file for writing, failing with `InvalidData` if its length or
+    /// alignment does not fit the layout.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be accessed, by this or any other process, other
+    /// than through this mapping while it is mapped.
+    pub unsafe fn map_mut(file: &File) -> io::Result<Self> {
+        let () = H::__ASSERT_NO_PADDING;
+        let () = T::__ASSERT_NO_PADDING;
+        let file = file.try_clone()?;
+        let map = MmapMut::map_mut(&file)?;
+        let len = validate::<H, T>(&map)?;
+
+        Ok(PodMmapMut {
+            map,
+            file,
+            len,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Borrows the header.
+    #[inline]
+    pub fn header(&self) -> &H {
+        unsafe { &*(self.map.as_ptr() as *const H) }
+    }
+
+    /// Mutably borrows the header.
+    #[inline]
+    pub fn header_mut(&mut self) -> &mut H {
+        unsafe { &mut *(self.map.as_mut_ptr() as *mut H) }
+    }
+
+    /// Flushes all outstanding changes to the file.
+    #[inline]
+    pub fn flush(&self) -> io::Result<()> {
+        self.map.flush()
+    }
+
+    /// Flushes outstanding changes to the records in `range` to the file.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds.
+    pub fn flush_range(&self, range: Range<usize>) -> io::Result<()> {
+        let len = size_of_slice(&self[range.clone()]);
+        self.map.flush_range(size_of::<H>() + range.start * size_of::<T>(), len)
+    }
+
+    /// Resizes the file to hold `len` records and remaps it. New records are
+    /// zeroed.
+    pub fn resize(&mut self, len: usize) -> io::Result<()> {
+        let size = len.checked_mul(size_of::<T>())
+            .and_then(|size| size.checked_add(size_of::<H>()))
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "mapping size overflows usize"))?;
+
+        self.flush()?;
+        // Never expose records past the end of the file if remapping fails
+        self.len = self.len.min(len);
+        if cfg!(not(unix)) {
+            // Other platforms do not allow resizing a mapped file, so swap in
+            // an empty stand-in that still covers the header
+            self.len = 0;
+            drop(mem::replace(&mut self.map, MmapMut::map_anon(size_of::<H>())?));
+        }
+        self.file.set_len(size as u64)?;
+        // The file is still covered by the contract of `map_mut`
+        self.map = unsafe { MmapMut::map_mut(&self.file)? };
+        self.len = len;
+        Ok(())
+    }
+
+    /// Appends records to the file, growing and remapping it once.
+    pub fn extend_from_slice(&mut self, data: &[T]) -> io::Result<()> {
+        let start = self.len;
+        self.resize(start + data.len())?;
+        for (record, value) in self[start..].iter_mut().zip(data) {
+            *record = value.copy();
+        }
+        Ok(())
+    }
+}
+
+impl<H: NoPadding, T: NoPadding> Deref for PodMmapMut<H, T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        unsafe { from_raw_parts(self.map.as_ptr().add(size_of::<H>()) as *const T, self.len) }
+    }
+}
+
+impl<H: NoPadding, T: NoPadding> DerefMut for PodMmapMut<H, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T] {
+        unsafe { from_raw_parts_mut(self.map.as_mut_ptr().add(size_of::<H>()) as *mut T, self.len) }
+    }
+}
+
+/// Compile-fail tests ensuring that padded headers and records cannot be
+/// written through a mapping.
+///
+/// ```compile_fail,E0080
+/// # extern crate pod;
+/// # use pod::{Pod, NoPadding};
+/// # use pod::mmap::PodMmapMut;
+/// # #[derive(Pod, NoPadding, Copy, Clone)]
+/// # #[repr(C)]
+/// # struct G<T>(u8, T);
+/// # fn main() -> std::io::Result<()> {
+/// # let file = std::fs::File::open("index")?;
+/// let mut map = unsafe { PodMmapMut::<G<u32>, u32>::map_mut(&file)? };
+/// *map.header_mut() = G(1, 2);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// ```compile_fail,E0080
+/// # extern crate pod;
+/// # use pod::{Pod, NoPadding};
+/// # use pod::mmap::PodMmapMut;
+/// # #[derive(Pod, NoPadding, Copy, Clone)]
+/// # #[repr(C)]
+/// # struct G<T>(u8, T);
+/// # fn main() -> std::io::Result<()> {
+/// # let file = std::fs::File::open("index")?;
+/// let mut map = unsafe { PodMmapMut::<(), G<u32>>::map_mut(&file)? };
+/// map[0] = G(1, 2);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(all(doctest, feature = "derive"))]
+mod padding_tests { }
@@ -1,22 +1,71 @@
-use std::ptr::{copy_nonoverlapping, read};
-use std::mem::{size_of, transmute, uninitialized, zeroed};
-use std::slice::{from_raw_parts, from_raw_parts_mut};
-use packed::{Unaligned, Aligned, is_aligned_for, is_aligned_for_slice, size_of_slice};
+use core::ptr::{read, read_unaligned};
+use core::mem::{align_of, size_of, zeroed, MaybeUninit};
+use core::num::{NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize};
+use core::num::{NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize};
+use core::ptr::NonNull;
+use core::num::{Wrapping, Saturating};
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
+use core::slice::{from_raw_parts, from_raw_parts_mut};
+#[cfg(feature = "alloc")]
+use core::ptr::{copy_nonoverlapping, slice_from_raw_parts_mut};
+#[cfg(feature = "alloc")]
+use alloc::alloc::{Layout, alloc, alloc_zeroed, handle_alloc_error};
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use endian::EndianConvert;
+use error::CastError;
+#[cfg(feature = "packed")]
+use packed::{Unaligned, Aligned};
 
 /// A marker trait indicating that a type is Plain Old Data.
 ///
 /// It is unsafe to `impl` this manually, use `#[derive(Pod)]` instead.
-pub unsafe trait Pod: Sized {
-    /// Generates a new uninitialized instance of a POD type.
+///
+/// # Safety
+///
+/// Every possible bit pattern must be a valid instance of the type, and it
+/// must not contain any references or interior mutability.
+pub unsafe trait Pod: Zeroable {
+    /// Creates an uninitialized instance of a POD type.
+    ///
+    /// It can be safely initialized with `as_uninit_bytes_mut`, or by writing
+    /// a value to it.
     #[inline]
-    unsafe fn uninitialized() -> Self {
-        uninitialized()
+    fn uninit() -> MaybeUninit<Self> {
+        MaybeUninit::uninit()
     }
 
-    /// Creates a new zeroed instance of a POD type.
+    /// Allocates a zeroed instance directly on the heap, without building it
+    /// on the stack first.
+    ///
+    /// This is the same as `Zeroable::zeroed_box`.
     #[inline]
-    fn zeroed() -> Self {
-        unsafe { zeroed() }
+    #[cfg(feature = "alloc")]
+    fn new_box_zeroed() -> Box<Self> {
+        Self::zeroed_box()
+    }
+
+    /// Allocates uninitialized storage for an instance directly on the heap.
+    ///
+    /// It can be safely initialized with `as_uninit_bytes_mut`.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn new_box_uninit() -> Box<MaybeUninit<Self>> {
+        let layout = Layout::new::<Self>();
+        if layout.size() == 0 {
+            return Box::new(Self::uninit())
+        }
+
+        unsafe {
+            let ptr = alloc(layout);
+            if ptr.is_null() {
+                handle_alloc_error(layout)
+            }
+            Box::from_raw(ptr as *mut MaybeUninit<Self>)
+        }
     }
 
     /// Creates a copy of this POD instance
@@ -31,47 +80,67 @@ pub unsafe trait Pod: Sized {
     ///
     /// Returns `None` if the two types are misaligned or not the same size.
     #[inline]
-    fn map<T: Pod>(&self) -> Option<&T> {
-        if size_of::<T>() == size_of::<Self>() && is_aligned_for::<T, _>(self) {
-            Some(unsafe { transmute(self) })
-        } else {
-            None
-        }
+    fn map<T: Pod>(&self) -> Option<&T> where Self: NoPadding {
+        self.cast().ok()
+    }
+
+    /// Converts a POD reference from one to another type of the same size.
+    ///
+    /// Fails if the two types are misaligned or not the same size.
+    #[inline]
+    fn cast<T: Pod>(&self) -> Result<&T, CastError> where Self: NoPadding {
+        let () = Self::__ASSERT_NO_PADDING;
+        CastError::check_size(size_of::<T>(), size_of::<Self>())?;
+        CastError::check_align::<T, _>(self)?;
+        Ok(unsafe { &*(self as *const Self as *const T) })
     }
 
     /// Converts a mutable POD reference from one to another type of the same size.
     ///
     /// Returns `None` if the two types are misaligned or not the same size.
     #[inline]
-    fn map_mut<T: Pod>(&mut self) -> Option<&mut T> {
-        if size_of::<T>() == size_of::<Self>() && is_aligned_for::<T, _>(self) {
-            Some(unsafe { transmute(self) })
-        } else {
-            None
-        }
+    fn map_mut<T: NoPadding>(&mut self) -> Option<&mut T> where Self: NoPadding {
+        self.cast_mut().ok()
+    }
+
+    /// Converts a mutable POD reference from one to another type of the same size.
+    ///
+    /// Fails if the two types are misaligned or not the same size.
+    #[inline]
+    fn cast_mut<T: NoPadding>(&mut self) -> Result<&mut T, CastError> where Self: NoPadding {
+        let () = Self::__ASSERT_NO_PADDING;
+        let () = T::__ASSERT_NO_PADDING;
+        CastError::check_size(size_of::<T>(), size_of::<Self>())?;
+        CastError::check_align::<T, _>(self)?;
+        Ok(unsafe { &mut *(self as *mut Self as *mut T) })
     }
 
     /// Converts a POD type from one to another of the same size.
     ///
     /// Returns `None` if the two types are not the same size.
     #[inline]
-    fn map_copy<T: Pod>(&self) -> Option<T> {
-        if size_of::<T>() == size_of::<Self>() {
-            Some(unsafe {
-                Pod::from_ptr(self)
-            })
-        } else {
-            None
-        }
+    fn map_copy<T: Pod>(&self) -> Option<T> where Self: NoPadding {
+        self.cast_copy().ok()
+    }
+
+    /// Converts a POD type from one to another of the same size.
+    ///
+    /// Fails if the two types are not the same size.
+    #[inline]
+    fn cast_copy<T: Pod>(&self) -> Result<T, CastError> where Self: NoPadding {
+        let () = Self::__ASSERT_NO_PADDING;
+        CastError::check_size(size_of::<T>(), size_of::<Self>())?;
+        Ok(unsafe { Pod::from_ptr(self) })
     }
 
     /// Converts a POD reference from one to another type of the same or lesser size.
     ///
     /// Returns `None` if the two types are misaligned or `T` is larger.
     #[inline]
-    fn try_map<T: Pod>(&self) -> Option<&T> {
+    fn try_map<T: Pod>(&self) -> Option<&T> where Self: NoPadding {
+        let () = Self::__ASSERT_NO_PADDING;
         if size_of::<T>() <= size_of::<Self>() && is_aligned_for::<T, _>(self) {
-            Some(unsafe { transmute(self) })
+            Some(unsafe { &*(self as *const Self as *const T) })
         } else {
             None
         }
@@ -81,9 +150,11 @@ pub unsafe trait Pod: Sized {
     ///
     /// Returns `None` if the two types are misaligned or `T` is larger.
     #[inline]
-    fn try_map_mut<T: Pod>(&mut self) -> Option<&mut T> {
+    fn try_map_mut<T: NoPadding>(&mut self) -> Option<&mut T> where Self: NoPadding {
+        let () = Self::__ASSERT_NO_PADDING;
+        let () = T::__ASSERT_NO_PADDING;
         if size_of::<T>() <= size_of::<Self>() && is_aligned_for::<T, _>(self) {
-            Some(unsafe { transmute(self) })
+            Some(unsafe { &mut *(self as *mut Self as *mut T) })
         } else {
             None
         }
@@ -93,7 +164,8 @@ pub unsafe trait Pod: Sized {
     ///
     /// Returns `None` if `T` is larger.
     #[inline]
-    fn try_map_copy<T: Pod>(&self) -> Option<T> {
+    fn try_map_copy<T: Pod>(&self) -> Option<T> where Self: NoPadding {
+        let () = Self::__ASSERT_NO_PADDING;
         if size_of::<T>() <= size_of::<Self>() {
             Some(unsafe {
                 Pod::from_ptr(self)
@@ -105,13 +177,25 @@ pub unsafe trait Pod: Sized {
 
     /// Converts a boxed POD type from one to another of the same size.
     ///
-    /// Fails if the two types are misaligned or not the same size.
+    /// Fails if the two types differ in alignment or are not the same size.
     #[inline]
-    fn map_box<T: Pod>(self: Box<Self>) -> Result<Box<T>, Box<Self>> {
-        if size_of::<T>() == size_of::<Self>() && is_aligned_for::<T, _>(&*self) {
-            Ok(unsafe { Box::from_raw(Box::into_raw(self) as *mut _) })
-        } else {
-            Err(self)
+    #[cfg(feature = "alloc")]
+    fn map_box<T: Pod>(self: Box<Self>) -> Result<Box<T>, Box<Self>> where Self: NoPadding {
+        Self::cast_box(self).map_err(|(_, s)| s)
+    }
+
+    /// Converts a boxed POD type from one to another of the same size.
+    ///
+    /// Fails if the two types differ in alignment or are not the same size,
+    /// returning the original box alongside the error.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn cast_box<T: Pod>(self: Box<Self>) -> Result<Box<T>, (CastError, Box<Self>)> where Self: NoPadding {
+        let () = Self::__ASSERT_NO_PADDING;
+        match CastError::check_size(size_of::<T>(), size_of::<Self>())
+            .and_then(|_| CastError::check_layout::<T, Self>()) {
+            Ok(()) => Ok(unsafe { Box::from_raw(Box::into_raw(self) as *mut T) }),
+            Err(e) => Err((e, self)),
         }
     }
 
@@ -119,35 +203,47 @@ pub unsafe trait Pod: Sized {
     ///
     /// Returns `None` if the types are misaligned or do not fit perfectly.
     #[inline]
-    fn split<T: Pod>(&self) -> Option<&[T]> {
-        if size_of::<Self>() % size_of::<T>() == 0 && is_aligned_for::<T, _>(self) {
-            Some(unsafe {
-                from_raw_parts(self as *const _ as *const T, size_of::<Self>() / size_of::<T>())
-            })
-        } else {
-            None
-        }
+    fn split<T: Pod>(&self) -> Option<&[T]> where Self: NoPadding {
+        self.cast_split().ok()
+    }
+
+    /// Converts a POD reference into a slice of another type.
+    ///
+    /// Fails if the types are misaligned or do not fit perfectly.
+    #[inline]
+    fn cast_split<T: Pod>(&self) -> Result<&[T], CastError> where Self: NoPadding {
+        let () = Self::__ASSERT_NO_PADDING;
+        let len = CastError::check_multiple(size_of::<T>(), size_of::<Self>())?;
+        CastError::check_align::<T, _>(self)?;
+        Ok(unsafe { from_raw_parts(self as *const _ as *const T, len) })
     }
 
     /// Converts a mutable POD reference into a slice of another type.
     ///
     /// Returns `None` if the types are misaligned or do not fit perfectly.
     #[inline]
-    fn split_mut<T: Pod>(&mut self) -> Option<&mut [T]> {
-        if size_of::<Self>() % size_of::<T>() == 0 && is_aligned_for::<T, _>(self) {
-            Some(unsafe {
-                from_raw_parts_mut(self as *mut _ as *mut T, size_of::<Self>() / size_of::<T>())
-            })
-        } else {
-            None
-        }
+    fn split_mut<T: NoPadding>(&mut self) -> Option<&mut [T]> where Self: NoPadding {
+        self.cast_split_mut().ok()
+    }
+
+    /// Converts a mutable POD reference into a slice of another type.
+    ///
+    /// Fails if the types are misaligned or do not fit perfectly.
+    #[inline]
+    fn cast_split_mut<T: NoPadding>(&mut self) -> Result<&mut [T], CastError> where Self: NoPadding {
+        let () = Self::__ASSERT_NO_PADDING;
+        let () = T::__ASSERT_NO_PADDING;
+        let len = CastError::check_multiple(size_of::<T>(), size_of::<Self>())?;
+        CastError::check_align::<T, _>(self)?;
+        Ok(unsafe { from_raw_parts_mut(self as *mut _ as *mut T, len) })
     }
 
     /// Converts a POD reference into a slice of another type.
     ///
     /// Returns an empty slice if the types are misaligned.
     #[inline]
-    fn try_split<T: Pod>(&self) -> &[T] {
+    fn try_split<T: Pod>(&self) -> &[T] where Self: NoPadding {
+        let () = Self::__ASSERT_NO_PADDING;
         if is_aligned_for::<T, _>(self) {
             unsafe {
                 from_raw_parts(self as *const _ as *const T, size_of::<Self>() / size_of::<T>())
@@ -161,7 +257,9 @@ pub unsafe trait Pod: Sized {
     ///
     /// Returns an empty slice if the types are misaligned.
     #[inline]
-    fn try_split_mut<T: Pod>(&mut self) -> &mut [T] {
+    fn try_split_mut<T: NoPadding>(&mut self) -> &mut [T] where Self: NoPadding {
+        let () = Self::__ASSERT_NO_PADDING;
+        let () = T::__ASSERT_NO_PADDING;
         if is_aligned_for::<T, _>(self) {
             unsafe {
                 from_raw_parts_mut(self as *mut _ as _, size_of::<Self>() / size_of::<T>())
@@ -173,64 +271,95 @@ pub unsafe trait Pod: Sized {
 
     /// Converts a boxed POD object into a boxed slice of another type.
     ///
-    /// Fails if the types are misaligned or do not fit perfectly.
+    /// Fails if the types differ in alignment or do not fit perfectly.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn split_box<T: Pod>(self: Box<Self>) -> Result<Box<[T]>, Box<Self>> where Self: NoPadding {
+        Self::cast_split_box(self).map_err(|(_, s)| s)
+    }
+
+    /// Converts a boxed POD object into a boxed slice of another type.
+    ///
+    /// Fails if the types differ in alignment or do not fit perfectly, returning
+    /// the original box alongside the error.
     #[inline]
-    fn split_box<T: Pod>(self: Box<Self>) -> Result<Box<[T]>, Box<Self>> {
-        if size_of::<Self>() % size_of::<T>() == 0 && is_aligned_for::<T, _>(&*self) {
-            Ok(unsafe {
+    #[cfg(feature = "alloc")]
+    fn cast_split_box<T: Pod>(self: Box<Self>) -> Result<Box<[T]>, (CastError, Box<Self>)> where Self: NoPadding {
+        let () = Self::__ASSERT_NO_PADDING;
+        match CastError::check_multiple(size_of::<T>(), size_of::<Self>())
+            .and_then(|len| CastError::check_layout::<T, Self>().map(|_| len)) {
+            Ok(len) => Ok(unsafe {
                 let ptr = Box::into_raw(self);
-                Box::from_raw(
-                    from_raw_parts_mut(ptr as _, size_of::<Self>() / size_of::<T>())
-                )
-            })
-        } else {
-            Err(self)
+                Box::from_raw(slice_from_raw_parts_mut(ptr as *mut T, len))
+            }),
+            Err(e) => Err((e, self)),
         }
     }
 
     /// Converts a boxed POD object into a vector of another type.
     ///
-    /// Fails if the types are misaligned or do not fit perfectly.
+    /// Fails if the types differ in alignment or do not fit perfectly.
     #[inline]
-    fn split_vec<T: Pod>(self: Box<Self>) -> Result<Vec<T>, Box<Self>> {
+    #[cfg(feature = "alloc")]
+    fn split_vec<T: Pod>(self: Box<Self>) -> Result<Vec<T>, Box<Self>> where Self: NoPadding {
         Self::split_box(self).map(|s| s.into_vec())
     }
 
+    /// Converts a boxed POD object into a vector of another type.
+    ///
+    /// Fails if the types differ in alignment or do not fit perfectly, returning
+    /// the original box alongside the error.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn cast_split_vec<T: Pod>(self: Box<Self>) -> Result<Vec<T>, (CastError, Box<Self>)> where Self: NoPadding {
+        Self::cast_split_box(self).map(|s| s.into_vec())
+    }
+
     /// Maps a POD slice from one type to another.
     ///
     /// Returns `None` if the slice is misaligned or the output type does not perfectly fit.
     #[inline]
-    fn map_slice<T: Pod>(s: &[Self]) -> Option<&[T]> {
-        let len = size_of_slice(s);
-        if is_aligned_for_slice::<T, _>(s) && len % size_of::<T>() == 0 {
-            Some(unsafe {
-                from_raw_parts(s.as_ptr() as _, len / size_of::<T>())
-            })
-        } else {
-            None
-        }
+    fn map_slice<T: Pod>(s: &[Self]) -> Option<&[T]> where Self: NoPadding {
+        Self::cast_slice(s).ok()
+    }
+
+    /// Maps a POD slice from one type to another.
+    ///
+    /// Fails if the slice is misaligned or the output type does not perfectly fit.
+    #[inline]
+    fn cast_slice<T: Pod>(s: &[Self]) -> Result<&[T], CastError> where Self: NoPadding {
+        let () = Self::__ASSERT_NO_PADDING;
+        let len = CastError::check_multiple(size_of::<T>(), size_of_slice(s))?;
+        CastError::check_align::<T, _>(s.as_ptr())?;
+        Ok(unsafe { from_raw_parts(s.as_ptr() as *const T, len) })
     }
 
     /// Maps a mutable POD slice from one type to another.
     ///
     /// Returns `None` if the slice is misaligned or the output type does not perfectly fit.
     #[inline]
-    fn map_slice_mut<T: Pod>(s: &mut [Self]) -> Option<&mut [T]> {
-        let len = size_of_slice(s);
-        if is_aligned_for_slice::<T, _>(s) && len % size_of::<T>() == 0 {
-            Some(unsafe {
-                from_raw_parts_mut(s.as_mut_ptr() as _, len / size_of::<T>())
-            })
-        } else {
-            None
-        }
+    fn map_slice_mut<T: NoPadding>(s: &mut [Self]) -> Option<&mut [T]> where Self: NoPadding {
+        Self::cast_slice_mut(s).ok()
+    }
+
+    /// Maps a mutable POD slice from one type to another.
+    ///
+    /// Fails if the slice is misaligned or the output type does not perfectly fit.
+    #[inline]
+    fn cast_slice_mut<T: NoPadding>(s: &mut [Self]) -> Result<&mut [T], CastError> where Self: NoPadding {
+        let () = Self::__ASSERT_NO_PADDING;
+        let () = T::__ASSERT_NO_PADDING;
+        let len = CastError::check_multiple(size_of::<T>(), size_of_slice(s))?;
+        CastError::check_align::<T, _>(s.as_ptr())?;
+        Ok(unsafe { from_raw_parts_mut(s.as_mut_ptr() as *mut T, len) })
     }
 
     /// Maps a POD slice from one type to another.
     ///
     /// Returns `None` if the slice is misaligned.
     #[inline]
-    fn try_map_slice<T: Pod>(s: &[Self]) -> &[T] {
+    fn try_map_slice<T: Pod>(s: &[Self]) -> &[T] where Self: NoPadding {
+        let () = Self::__ASSERT_NO_PADDING;
         let len = size_of_slice(s);
         if is_aligned_for_slice::<T, _>(s) {
             unsafe {
@@ -245,7 +374,9 @@ pub unsafe trait Pod: Sized {
     ///
     /// Returns `None` if the slice is misaligned.
     #[inline]
-    fn try_map_slice_mut<T: Pod>(s: &mut [Self]) -> &mut [T] {
+    fn try_map_slice_mut<T: NoPadding>(s: &mut [Self]) -> &mut [T] where Self: NoPadding {
+        let () = Self::__ASSERT_NO_PADDING;
+        let () = T::__ASSERT_NO_PADDING;
         let len = size_of_slice(s);
         if is_aligned_for_slice::<T, _>(s) {
             unsafe {
@@ -258,75 +389,159 @@ pub unsafe trait Pod: Sized {
 
     /// Maps a boxed POD slice from one type to another.
     ///
-    /// Fails if the slice is misaligned or does not perfectly fit.
+    /// Fails if the types differ in alignment or do not perfectly fit.
     #[inline]
-    fn map_slice_box<T: Pod>(s: Box<[Self]>) -> Result<Box<[T]>, Box<[Self]>> {
-        let len = size_of_slice(&s);
-        if is_aligned_for_slice::<T, _>(&s) && len % size_of::<T>() == 0 {
-            Ok(unsafe {
+    #[cfg(feature = "alloc")]
+    fn map_slice_box<T: Pod>(s: Box<[Self]>) -> Result<Box<[T]>, Box<[Self]>> where Self: NoPadding {
+        Self::cast_slice_box(s).map_err(|(_, s)| s)
+    }
+
+    /// Maps a boxed POD slice from one type to another.
+    ///
+    /// Fails if the types differ in alignment or do not perfectly fit, returning
+    /// the original slice alongside the error.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    #[allow(clippy::type_complexity)]
+    fn cast_slice_box<T: Pod>(s: Box<[Self]>) -> Result<Box<[T]>, (CastError, Box<[Self]>)> where Self: NoPadding {
+        let () = Self::__ASSERT_NO_PADDING;
+        match CastError::check_multiple(size_of::<T>(), size_of_slice(&s))
+            .and_then(|len| CastError::check_layout::<T, Self>().map(|_| len)) {
+            Ok(len) => Ok(unsafe {
                 let ptr = Box::into_raw(s);
-                Box::from_raw(
-                    from_raw_parts_mut(ptr as _, len / size_of::<T>())
-                )
-            })
-        } else {
-            Err(s)
+                Box::from_raw(slice_from_raw_parts_mut(ptr as *mut T, len))
+            }),
+            Err(e) => Err((e, s)),
         }
     }
 
+    /// Maps a POD vector from one type to another, dropping any spare
+    /// capacity. See `cast_vec` to keep it.
+    ///
+    /// Fails if the types differ in alignment or do not perfectly fit.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn map_slice_vec<T: Pod>(s: Vec<Self>) -> Result<Vec<T>, Vec<Self>> where Self: NoPadding {
+        Self::cast_slice_vec(s).map_err(|(_, s)| s)
+    }
+
     /// Maps a POD vector from one type to another.
     ///
-    /// Fails if the slice is misaligned or does not perfectly fit.
+    /// Fails if the types differ in alignment or do not perfectly fit, returning
+    /// the original vector alongside the error.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn cast_slice_vec<T: Pod>(s: Vec<Self>) -> Result<Vec<T>, (CastError, Vec<Self>)> where Self: NoPadding {
+        Self::cast_slice_box(s.into_boxed_slice()).map(|s| s.into_vec()).map_err(|(e, s)| (e, s.into_vec()))
+    }
+
+    /// Maps a POD vector from one type to another in place, keeping its spare
+    /// capacity.
+    ///
+    /// Fails if the types differ in alignment, or if either the length or the
+    /// capacity does not perfectly fit, returning the original vector
+    /// alongside the error.
     #[inline]
-    fn map_slice_vec<T: Pod>(s: Vec<Self>) -> Result<Vec<T>, Vec<Self>> {
-        Self::map_slice_box(s.into_boxed_slice()).map_err(|s| s.into_vec()).map(|s| s.into_vec())
+    #[cfg(feature = "alloc")]
+    fn cast_vec<T: Pod>(s: Vec<Self>) -> Result<Vec<T>, (CastError, Vec<Self>)> where Self: NoPadding {
+        let () = Self::__ASSERT_NO_PADDING;
+        let capacity = size_of::<Self>() * s.capacity();
+        let res = CastError::check_multiple(size_of::<T>(), size_of_slice(&s))
+            .and_then(|len| CastError::check_multiple(size_of::<T>(), capacity).map(|cap| (len, cap)))
+            .and_then(|lens| CastError::check_layout::<T, Self>().map(|_| lens));
+        match res {
+            Ok((len, cap)) => Ok(unsafe {
+                let mut s = ManuallyDrop::new(s);
+                Vec::from_raw_parts(s.as_mut_ptr() as *mut T, len, cap)
+            }),
+            Err(e) => Err((e, s)),
+        }
+    }
+
+    /// Maps a boxed POD slice from one type to another, moving it into a new
+    /// allocation if the types differ in alignment.
+    ///
+    /// Fails if the output type does not perfectly fit, returning the original
+    /// slice alongside the error.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    #[allow(clippy::type_complexity)]
+    fn realloc_cast<T: Pod>(s: Box<[Self]>) -> Result<Box<[T]>, (CastError, Box<[Self]>)> where Self: NoPadding {
+        match Self::cast_slice_box(s) {
+            Err((CastError::Layout { .. }, s)) => {
+                let mut data = T::zeroed_vec(size_of_slice(&s) / size_of::<T>());
+                unsafe { copy_nonoverlapping(s.as_ptr() as *const u8, data.as_mut_ptr() as *mut u8, size_of_slice(&s)) };
+                Ok(data.into_boxed_slice())
+            },
+            res => res,
+        }
     }
 
     /// Converts a POD slice into another type.
     ///
     /// Returns `None` if the types are misaligned or not the same size.
     #[inline]
-    fn merge<T: Pod>(s: &[Self]) -> Option<&T> {
-        if is_aligned_for_slice::<T, _>(s) && size_of_slice(s) == size_of::<T>() {
-            Some(unsafe { transmute(s.as_ptr()) })
-        } else {
-            None
-        }
+    fn merge<T: Pod>(s: &[Self]) -> Option<&T> where Self: NoPadding {
+        Self::cast_merge(s).ok()
+    }
+
+    /// Converts a POD slice into another type.
+    ///
+    /// Fails if the types are misaligned or not the same size.
+    #[inline]
+    fn cast_merge<T: Pod>(s: &[Self]) -> Result<&T, CastError> where Self: NoPadding {
+        let () = Self::__ASSERT_NO_PADDING;
+        CastError::check_size(size_of::<T>(), size_of_slice(s))?;
+        CastError::check_align::<T, _>(s.as_ptr())?;
+        Ok(unsafe { &*(s.as_ptr() as *const T) })
     }
 
     /// Converts a mutable POD slice into another type.
     ///
     /// Returns `None` if the types are misaligned or not the same size.
     #[inline]
-    fn merge_mut<T: Pod>(s: &mut [Self]) -> Option<&mut T> {
-        if is_aligned_for_slice::<T, _>(s) && size_of_slice(s) == size_of::<T>() {
-            Some(unsafe { transmute(s.as_mut_ptr()) })
-        } else {
-            None
-        }
+    fn merge_mut<T: NoPadding>(s: &mut [Self]) -> Option<&mut T> where Self: NoPadding {
+        Self::cast_merge_mut(s).ok()
+    }
+
+    /// Converts a mutable POD slice into another type.
+    ///
+    /// Fails if the types are misaligned or not the same size.
+    #[inline]
+    fn cast_merge_mut<T: NoPadding>(s: &mut [Self]) -> Result<&mut T, CastError> where Self: NoPadding {
+        let () = Self::__ASSERT_NO_PADDING;
+        let () = T::__ASSERT_NO_PADDING;
+        CastError::check_size(size_of::<T>(), size_of_slice(s))?;
+        CastError::check_align::<T, _>(s.as_ptr())?;
+        Ok(unsafe { &mut *(s.as_mut_ptr() as *mut T) })
     }
 
     /// Converts a POD slice into another type.
     ///
     /// Returns `None` if the types are not the same size.
     #[inline]
-    fn merge_copy<T: Pod>(s: &[Self]) -> Option<T> {
-        if size_of_slice(s) == size_of::<T>() {
-            Some(unsafe {
-                Pod::from_ptr(s.as_ptr())
-            })
-        } else {
-            None
-        }
+    fn merge_copy<T: Pod>(s: &[Self]) -> Option<T> where Self: NoPadding {
+        Self::cast_merge_copy(s).ok()
+    }
+
+    /// Converts a POD slice into another type.
+    ///
+    /// Fails if the types are not the same size.
+    #[inline]
+    fn cast_merge_copy<T: Pod>(s: &[Self]) -> Result<T, CastError> where Self: NoPadding {
+        let () = Self::__ASSERT_NO_PADDING;
+        CastError::check_size(size_of::<T>(), size_of_slice(s))?;
+        Ok(unsafe { Pod::from_ptr(s.as_ptr()) })
     }
 
     /// Converts a POD slice into another type.
     ///
     /// Returns `None` if the types are misaligned or `T` is larger.
     #[inline]
-    fn try_merge<T: Pod>(s: &[Self]) -> Option<&T> {
+    fn try_merge<T: Pod>(s: &[Self]) -> Option<&T> where Self: NoPadding {
+        let () = Self::__ASSERT_NO_PADDING;
         if is_aligned_for_slice::<T, _>(s) && size_of_slice(s) >= size_of::<T>() {
-            Some(unsafe { transmute(s.as_ptr()) })
+            Some(unsafe { &*(s.as_ptr() as *const T) })
         } else {
             None
         }
@@ -336,9 +551,11 @@ pub unsafe trait Pod: Sized {
     ///
     /// Returns `None` if the types are misaligned or `T` is larger.
     #[inline]
-    fn try_merge_mut<T: Pod>(s: &mut [Self]) -> Option<&mut T> {
+    fn try_merge_mut<T: NoPadding>(s: &mut [Self]) -> Option<&mut T> where Self: NoPadding {
+        let () = Self::__ASSERT_NO_PADDING;
+        let () = T::__ASSERT_NO_PADDING;
         if is_aligned_for_slice::<T, _>(s) && size_of_slice(s) >= size_of::<T>() {
-            Some(unsafe { transmute(s.as_mut_ptr()) })
+            Some(unsafe { &mut *(s.as_mut_ptr() as *mut T) })
         } else {
             None
         }
@@ -348,8 +565,9 @@ pub unsafe trait Pod: Sized {
     ///
     /// Returns `None` if `T` is larger.
     #[inline]
-    fn try_merge_copy<T: Pod>(s: &[Self]) -> Option<T> {
-        if size_of_slice(s) <= size_of::<T>() {
+    fn try_merge_copy<T: Pod>(s: &[Self]) -> Option<T> where Self: NoPadding {
+        let () = Self::__ASSERT_NO_PADDING;
+        if size_of_slice(s) >= size_of::<T>() {
             Some(unsafe {
                 Pod::from_ptr(s.as_ptr())
             })
@@ -360,52 +578,78 @@ pub unsafe trait Pod: Sized {
 
     /// Converts a boxed POD slice into another boxed type.
     ///
-    /// Fails if the types are misaligned or not the same size.
+    /// Fails if the types differ in alignment or are not the same size.
     #[inline]
-    fn merge_box<T: Pod>(s: Box<[Self]>) -> Result<Box<T>, Box<[Self]>> {
-        if is_aligned_for_slice::<T, _>(&s) && size_of_slice(&s) == size_of::<T>() {
-            Ok(unsafe {
+    #[cfg(feature = "alloc")]
+    fn merge_box<T: Pod>(s: Box<[Self]>) -> Result<Box<T>, Box<[Self]>> where Self: NoPadding {
+        Self::cast_merge_box(s).map_err(|(_, s)| s)
+    }
+
+    /// Converts a boxed POD slice into another boxed type.
+    ///
+    /// Fails if the types differ in alignment or are not the same size, returning
+    /// the original slice alongside the error.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn cast_merge_box<T: Pod>(s: Box<[Self]>) -> Result<Box<T>, (CastError, Box<[Self]>)> where Self: NoPadding {
+        let () = Self::__ASSERT_NO_PADDING;
+        match CastError::check_size(size_of::<T>(), size_of_slice(&s))
+            .and_then(|_| CastError::check_layout::<T, Self>()) {
+            Ok(()) => Ok(unsafe {
                 let ptr = (*Box::into_raw(s)).as_mut_ptr();
-                Box::from_raw(ptr as _)
-            })
-        } else {
-            Err(s)
+                Box::from_raw(ptr as *mut T)
+            }),
+            Err(e) => Err((e, s)),
         }
     }
 
     /// Converts a POD vector into another boxed type.
     ///
-    /// Fails if the types are misaligned or not the same size.
+    /// Fails if the types differ in alignment or are not the same size.
     #[inline]
-    fn merge_vec<T: Pod>(s: Vec<Self>) -> Result<Box<T>, Vec<Self>> {
-        Self::merge_box(s.into_boxed_slice()).map_err(|s| s.into_vec())
+    #[cfg(feature = "alloc")]
+    fn merge_vec<T: Pod>(s: Vec<Self>) -> Result<Box<T>, Vec<Self>> where Self: NoPadding {
+        Self::cast_merge_vec(s).map_err(|(_, s)| s)
+    }
+
+    /// Converts a POD vector into another boxed type.
+    ///
+    /// Fails if the types differ in alignment or are not the same size, returning
+    /// the original vector alongside the error.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn cast_merge_vec<T: Pod>(s: Vec<Self>) -> Result<Box<T>, (CastError, Vec<Self>)> where Self: NoPadding {
+        Self::cast_merge_box(s.into_boxed_slice()).map_err(|(e, s)| (e, s.into_vec()))
     }
 
     /// Creates a new POD instance from an unaligned pointer.
     ///
     /// This is an unsafe operation because the pointer is not validated in any way.
+    ///
+    /// # Safety
+    ///
+    /// `source` must be valid for reads of `size_of::<Self>()` bytes.
     #[inline]
     unsafe fn from_ptr<T>(source: *const T) -> Self {
-        let mut s = Self::uninitialized();
-        copy_nonoverlapping(source as *const u8, &mut s as *mut _ as _, size_of::<Self>());
-        s
+        read_unaligned(source as *const Self)
     }
 
     /// Creates a new POD instance with the inverse of `map_copy()`
     #[inline]
-    fn from_ref<T: Pod>(p: &T) -> Option<Self> {
+    fn from_ref<T: NoPadding>(p: &T) -> Option<Self> {
         Pod::map_copy(p)
     }
 
     /// Creates a new POD instance with the inverse of `merge_copy()`
     #[inline]
-    fn from_slice<T: Pod>(p: &[T]) -> Option<Self> {
+    fn from_slice<T: NoPadding>(p: &[T]) -> Option<Self> {
         Pod::merge_copy(p)
     }
 
     /// Creates a new POD instance with the inverse of `merge_box()`
     #[inline]
-    fn from_boxed_slice<T: Pod>(p: Box<[T]>) -> Result<Box<Self>, Box<[T]>> {
+    #[cfg(feature = "alloc")]
+    fn from_boxed_slice<T: NoPadding>(p: Box<[T]>) -> Result<Box<Self>, Box<[T]>> {
         Pod::merge_box(p).or_else(|p|
             Pod::merge_copy(&p).map(Box::new).ok_or(p)
         )
@@ -413,58 +657,84 @@ pub unsafe trait Pod: Sized {
 
     /// Creates a new POD instance with the inverse of `merge_vec()`
     #[inline]
-    fn from_vec<T: Pod>(p: Vec<T>) -> Result<Box<Self>, Vec<T>> {
+    #[cfg(feature = "alloc")]
+    fn from_vec<T: NoPadding>(p: Vec<T>) -> Result<Box<Self>, Vec<T>> {
         Pod::from_boxed_slice(p.into_boxed_slice()).map_err(|p| p.into_vec())
     }
 
     /// Creates a new POD instance with the inverse of `map_slice_box()`
     #[inline]
-    fn slice_from_boxed_slice<T: Pod>(p: Box<[T]>) -> Result<Box<[Self]>, Box<[T]>> {
+    #[cfg(feature = "alloc")]
+    fn slice_from_boxed_slice<T: NoPadding>(p: Box<[T]>) -> Result<Box<[Self]>, Box<[T]>> {
         Pod::map_slice_box(p)
     }
 
     /// Creates a POD reference with the inverse of `map()`
     #[inline]
-    fn ref_from<T: Pod>(p: &T) -> Option<&Self> {
+    fn ref_from<T: NoPadding>(p: &T) -> Option<&Self> {
         Pod::map(p)
     }
 
     /// Creates a mutable POD reference with the inverse of `map_mut()`
     #[inline]
-    fn ref_from_mut<T: Pod>(p: &mut T) -> Option<&mut Self> {
+    fn ref_from_mut<T: NoPadding>(p: &mut T) -> Option<&mut Self> where Self: NoPadding {
         Pod::map_mut(p)
     }
 
     /// Creates a POD reference with the inverse of `merge()`
     #[inline]
-    fn ref_from_slice<T: Pod>(p: &[T]) -> Option<&Self> {
+    fn ref_from_slice<T: NoPadding>(p: &[T]) -> Option<&Self> {
         Pod::merge(p)
     }
 
     /// Creates a mutable POD reference with the inverse of `merge_mut()`
     #[inline]
-    fn ref_from_slice_mut<T: Pod>(p: &mut [T]) -> Option<&mut Self> {
+    fn ref_from_slice_mut<T: NoPadding>(p: &mut [T]) -> Option<&mut Self> where Self: NoPadding {
         Pod::merge_mut(p)
     }
 
     /// Borrows the POD as a byte slice
     #[inline]
-    fn as_bytes(&self) -> &[u8] {
+    fn as_bytes(&self) -> &[u8] where Self: NoPadding {
+        let () = Self::__ASSERT_NO_PADDING;
         self.try_split()
     }
 
     /// Borrows the POD as a mutable byte slice
     #[inline]
-    fn as_bytes_mut(&mut self) -> &mut [u8] {
+    fn as_bytes_mut(&mut self) -> &mut [u8] where Self: NoPadding {
+        let () = Self::__ASSERT_NO_PADDING;
         self.try_split_mut()
     }
 
+    /// Borrows a POD slice as a byte slice
+    #[inline]
+    fn slice_as_bytes(s: &[Self]) -> &[u8] where Self: NoPadding {
+        let () = Self::__ASSERT_NO_PADDING;
+        Self::try_map_slice(s)
+    }
+
+    /// Borrows a mutable POD slice as a mutable byte slice
+    #[inline]
+    fn slice_as_bytes_mut(s: &mut [Self]) -> &mut [u8] where Self: NoPadding {
+        let () = Self::__ASSERT_NO_PADDING;
+        Self::try_map_slice_mut(s)
+    }
+
     /// Safely creates a POD value from a potentially unaligned slice
     ///
     /// Returns `None` if `slice.len()` is not the same as the type's size
     #[inline]
     fn from_bytes(p: &[u8]) -> Option<Self> {
-        Self::from_slice(&p)
+        Self::from_slice(p)
+    }
+
+    /// Safely creates a POD value from a potentially unaligned slice
+    ///
+    /// Fails if `slice.len()` is not the same as the type's size
+    #[inline]
+    fn cast_from_bytes(p: &[u8]) -> Result<Self, CastError> {
+        Pod::cast_merge_copy(p)
     }
 
     /// Borrows a new instance of the POD from a byte slice
@@ -475,46 +745,172 @@ pub unsafe trait Pod: Sized {
         Self::ref_from_slice(p)
     }
 
+    /// Borrows a new instance of the POD from a byte slice
+    ///
+    /// Fails if the slice is misaligned or `slice.len()` is not the same as
+    /// the type's size
+    #[inline]
+    fn cast_ref_from_bytes(p: &[u8]) -> Result<&Self, CastError> {
+        Pod::cast_merge(p)
+    }
+
     /// Borrows a mutable instance of the POD from a mutable byte slice
     ///
     /// Returns `None` if `slice.len()` is not the same as the type's size
     #[inline]
-    fn ref_from_bytes_mut(p: &mut [u8]) -> Option<&mut Self> {
+    fn ref_from_bytes_mut(p: &mut [u8]) -> Option<&mut Self> where Self: NoPadding {
         Self::ref_from_slice_mut(p)
     }
 
+    /// Borrows a mutable instance of the POD from a mutable byte slice
+    ///
+    /// Fails if the slice is misaligned or `slice.len()` is not the same as
+    /// the type's size
+    #[inline]
+    fn cast_ref_from_bytes_mut(p: &mut [u8]) -> Result<&mut Self, CastError> where Self: NoPadding {
+        Pod::cast_merge_mut(p)
+    }
+
+    /// Safely creates a POD value from the start of a potentially unaligned
+    /// slice, returning it along with the remaining bytes
+    ///
+    /// Returns `None` if `slice.len()` is smaller than the type's size
+    #[inline]
+    fn from_prefix(p: &[u8]) -> Option<(Self, &[u8])> {
+        if p.len() < size_of::<Self>() {
+            return None
+        }
+
+        let (head, tail) = p.split_at(size_of::<Self>());
+        Self::from_bytes(head).map(|v| (v, tail))
+    }
+
+    /// Safely creates a POD value from the end of a potentially unaligned
+    /// slice, returning it along with the preceding bytes
+    ///
+    /// Returns `None` if `slice.len()` is smaller than the type's size
+    #[inline]
+    fn from_suffix(p: &[u8]) -> Option<(&[u8], Self)> {
+        let (head, tail) = p.split_at(p.len().checked_sub(size_of::<Self>())?);
+        Self::from_bytes(tail).map(|v| (head, v))
+    }
+
+    /// Borrows an instance of the POD from the start of a byte slice,
+    /// returning it along with the remaining bytes
+    ///
+    /// Returns `None` if the slice is misaligned or `slice.len()` is smaller
+    /// than the type's size
+    #[inline]
+    fn ref_from_prefix(p: &[u8]) -> Option<(&Self, &[u8])> {
+        if p.len() < size_of::<Self>() {
+            return None
+        }
+
+        let (head, tail) = p.split_at(size_of::<Self>());
+        Self::ref_from_bytes(head).map(|v| (v, tail))
+    }
+
+    /// Borrows an instance of the POD from the end of a byte slice,
+    /// returning it along with the preceding bytes
+    ///
+    /// Returns `None` if the end of the slice is misaligned or `slice.len()`
+    /// is smaller than the type's size
+    #[inline]
+    fn ref_from_suffix(p: &[u8]) -> Option<(&[u8], &Self)> {
+        let (head, tail) = p.split_at(p.len().checked_sub(size_of::<Self>())?);
+        Self::ref_from_bytes(tail).map(|v| (head, v))
+    }
+
+    /// Borrows a mutable instance of the POD from the start of a mutable byte
+    /// slice, returning it along with the remaining bytes
+    ///
+    /// Returns `None` if the slice is misaligned or `slice.len()` is smaller
+    /// than the type's size
+    #[inline]
+    fn ref_from_prefix_mut(p: &mut [u8]) -> Option<(&mut Self, &mut [u8])> where Self: NoPadding {
+        if p.len() < size_of::<Self>() {
+            return None
+        }
+
+        let (head, tail) = p.split_at_mut(size_of::<Self>());
+        Self::ref_from_bytes_mut(head).map(|v| (v, tail))
+    }
+
+    /// Borrows a mutable instance of the POD from the end of a mutable byte
+    /// slice, returning it along with the preceding bytes
+    ///
+    /// Returns `None` if the end of the slice is misaligned or `slice.len()`
+    /// is smaller than the type's size
+    #[inline]
+    fn ref_from_suffix_mut(p: &mut [u8]) -> Option<(&mut [u8], &mut Self)> where Self: NoPadding {
+        let mid = p.len().checked_sub(size_of::<Self>())?;
+        let (head, tail) = p.split_at_mut(mid);
+        Self::ref_from_bytes_mut(tail).map(|v| (head, v))
+    }
+
     /// Converts a boxed slice to a boxed instance of the POD type
     ///
     /// Fails if `slice.len()` is not the same as the type's size
     #[inline]
+    #[cfg(feature = "alloc")]
     fn from_byte_slice(p: Box<[u8]>) -> Result<Box<Self>, Box<[u8]>> {
         Self::from_boxed_slice(p)
     }
 
+    /// Converts a boxed slice to a boxed instance of the POD type, copying
+    /// it unless the type has an alignment of 1
+    ///
+    /// Fails if `slice.len()` is not the same as the type's size, returning
+    /// the original slice alongside the error
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn cast_from_byte_slice(p: Box<[u8]>) -> Result<Box<Self>, (CastError, Box<[u8]>)> {
+        match Pod::cast_merge_box(p) {
+            Err((CastError::Layout { .. }, p)) => Ok(Box::new(unsafe { Pod::from_ptr(p.as_ptr()) })),
+            res => res,
+        }
+    }
+
     /// Converts a byte vector to a boxed instance of the POD type
     ///
     /// Fails if `vec.len()` is not the same as the type's size
     #[inline]
+    #[cfg(feature = "alloc")]
     fn from_byte_vec(p: Vec<u8>) -> Result<Box<Self>, Vec<u8>> {
         Self::from_vec(p)
     }
 
+    /// Converts a byte vector to a boxed instance of the POD type, copying
+    /// it unless the type has an alignment of 1
+    ///
+    /// Fails if `vec.len()` is not the same as the type's size, returning the
+    /// original vector alongside the error
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn cast_from_byte_vec(p: Vec<u8>) -> Result<Box<Self>, (CastError, Vec<u8>)> {
+        Self::cast_from_byte_slice(p.into_boxed_slice()).map_err(|(e, p)| (e, p.into_vec()))
+    }
+
     /// Converts a boxed POD to a boxed slice
     #[inline]
-    fn into_byte_slice(self: Box<Self>) -> Box<[u8]> {
-        Self::split_box(self).ok().unwrap()
+    #[cfg(feature = "alloc")]
+    fn into_byte_slice(self: Box<Self>) -> Box<[u8]> where Self: NoPadding {
+        let () = Self::__ASSERT_NO_PADDING;
+        Self::split_box(self).unwrap_or_else(|s| s.as_bytes().into())
     }
 
     /// Converts a boxed POD to a byte vector
     #[inline]
-    fn into_byte_vec(self: Box<Self>) -> Vec<u8> {
-        Self::split_vec(self).ok().unwrap()
+    #[cfg(feature = "alloc")]
+    fn into_byte_vec(self: Box<Self>) -> Vec<u8> where Self: NoPadding {
+        Self::into_byte_slice(self).into_vec()
     }
 
     /// Safely borrows the aligned value mutably
     ///
     /// See also: `Aligned::from_unaligned_mut`
     #[inline]
+    #[cfg(feature = "packed")]
     fn as_aligned_mut<T: Pod + Aligned<Unaligned=Self>>(&mut self) -> Option<&mut T> where Self: Copy + Unaligned {
         unsafe { Aligned::from_unaligned_mut(self) }
     }
@@ -523,6 +919,7 @@ pub unsafe trait Pod: Sized {
     ///
     /// See also: `Aligned::from_unaligned_mut`
     #[inline]
+    #[cfg(feature = "packed")]
     fn from_unaligned_mut<T: Copy + Unaligned>(s: &mut T) -> Option<&mut Self> where Self: Aligned<Unaligned=T> {
         unsafe { Aligned::from_unaligned_mut(s) }
     }
@@ -531,6 +928,7 @@ pub unsafe trait Pod: Sized {
     ///
     /// See also: `Aligned::from_unaligned`
     #[inline]
+    #[cfg(feature = "packed")]
     fn from_unaligned<T: Copy + Unaligned>(s: T) -> Self where Self: Aligned<Unaligned=T> {
         unsafe { Aligned::from_unaligned(s) }
     }
@@ -539,6 +937,155 @@ pub unsafe trait Pod: Sized {
     fn __assert_pod() { }
 }
 
+/// A marker trait indicating that a type may be safely initialized with zeroes.
+///
+/// All `Pod` types are `Zeroable`, but so are many types with invalid bit
+/// patterns such as `bool`, `Option<NonZeroU32>` and `Option<&T>`.
+///
+/// # Safety
+///
+/// A value with every byte set to zero must be a valid instance of the type.
+pub unsafe trait Zeroable: Sized {
+    /// Creates a new zeroed instance of the type.
+    #[inline]
+    fn zeroed() -> Self {
+        unsafe { zeroed() }
+    }
+
+    /// Allocates a zeroed instance directly on the heap.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn zeroed_box() -> Box<Self> {
+        let layout = Layout::new::<Self>();
+        if layout.size() == 0 {
+            return Box::new(Self::zeroed())
+        }
+
+        unsafe {
+            let ptr = alloc_zeroed(layout);
+            if ptr.is_null() {
+                handle_alloc_error(layout)
+            }
+            Box::from_raw(ptr as *mut Self)
+        }
+    }
+
+    /// Allocates a vector of `len` zeroed instances directly from the allocator.
+    ///
+    /// Panics if the size of the vector overflows `isize::MAX`.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn zeroed_vec(len: usize) -> Vec<Self> {
+        let mut data = Vec::new();
+        if size_of::<Self>() == 0 || len == 0 {
+            // Zero-sized values take up no room in the vector
+            unsafe { data.set_len(len) };
+            return data
+        }
+
+        let layout = Layout::array::<Self>(len).expect("capacity overflow");
+        unsafe {
+            let ptr = alloc_zeroed(layout);
+            if ptr.is_null() {
+                handle_alloc_error(layout)
+            }
+            Vec::from_raw_parts(ptr as *mut Self, len, len)
+        }
+    }
+
+    #[doc(hidden)]
+    fn __assert_zeroable() { }
+}
+
+/// A marker trait indicating that a `Pod` type contains no padding bytes.
+///
+/// Only types without padding may be viewed as bytes, as reading padding is
+/// undefined behaviour. Use `#[derive(NoPadding)]` to verify this at compile
+/// time.
+///
+/// Every `Pod` conversion that exposes the memory of a value as another type
+/// requires the source type to be `NoPadding`. Mutable conversions require
+/// both types to be, as writing a padded value through one leaves
+/// uninitialized bytes behind in the other.
+///
+/// # Safety
+///
+/// The size of the type must be equal to the sum of the sizes of its fields,
+/// which must also be `NoPadding`.
+pub unsafe trait NoPadding: Pod {
+    #[doc(hidden)]
+    const __ASSERT_NO_PADDING: () = ();
+}
+
+/// An object safe view of a `NoPadding` value or slice as bytes, allowing
+/// values of different types to be passed together.
+pub trait AsPodBytes {
+    /// Borrows the value as a byte slice.
+    fn as_pod_bytes(&self) -> &[u8];
+}
+
+/// An object safe view of a mutable `NoPadding` value or slice as bytes,
+/// allowing values of different types to be passed together.
+pub trait AsPodBytesMut: AsPodBytes {
+    /// Borrows the value as a mutable byte slice.
+    fn as_pod_bytes_mut(&mut self) -> &mut [u8];
+}
+
+impl<P: NoPadding> AsPodBytes for P {
+    #[inline]
+    fn as_pod_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<P: NoPadding> AsPodBytesMut for P {
+    #[inline]
+    fn as_pod_bytes_mut(&mut self) -> &mut [u8] {
+        self.as_bytes_mut()
+    }
+}
+
+impl<P: NoPadding> AsPodBytes for [P] {
+    #[inline]
+    fn as_pod_bytes(&self) -> &[u8] {
+        P::slice_as_bytes(self)
+    }
+}
+
+impl<P: NoPadding> AsPodBytesMut for [P] {
+    #[inline]
+    fn as_pod_bytes_mut(&mut self) -> &mut [u8] {
+        P::slice_as_bytes_mut(self)
+    }
+}
+
+/// Determines whether a pointer is correctly aligned for type `T`.
+#[inline]
+pub(crate) fn is_aligned_for<T, U>(ptr: *const U) -> bool {
+    align_of::<U>() >= align_of::<T>() || ptr as usize % align_of::<T>() == 0
+}
+
+/// Determines whether a slice is correctly aligned for type `T`.
+#[inline]
+pub(crate) fn is_aligned_for_slice<T, U>(slice: &[U]) -> bool {
+    is_aligned_for::<T, _>(slice.as_ptr())
+}
+
+/// Calculates the total byte size of a slice.
+#[inline]
+pub(crate) fn size_of_slice<T>(slice: &[T]) -> usize {
+    core::mem::size_of_val(slice)
+}
+
+/// Borrows the memory of a possibly uninitialized value as bytes.
+///
+/// Once every byte has been written to, the value may be assumed to be
+/// initialized if it is `Pod`.
+#[inline]
+pub fn as_uninit_bytes_mut<T>(p: &mut MaybeUninit<T>) -> &mut [MaybeUninit<u8>] {
+    unsafe { from_raw_parts_mut(p.as_mut_ptr() as *mut MaybeUninit<u8>, size_of::<T>()) }
+}
+
 unsafe impl Pod for () { }
 unsafe impl Pod for f32 { }
 unsafe impl Pod for f64 { }
@@ -550,26 +1097,465 @@ unsafe impl Pod for i32 { }
 unsafe impl Pod for u32 { }
 unsafe impl Pod for i64 { }
 unsafe impl Pod for u64 { }
+unsafe impl Pod for i128 { }
+unsafe impl Pod for u128 { }
 unsafe impl Pod for isize { }
 unsafe impl Pod for usize { }
 unsafe impl<T> Pod for *const T { }
 unsafe impl<T> Pod for *mut T { }
+unsafe impl<T: ?Sized> Pod for PhantomData<T> { }
+unsafe impl<T: Pod> Pod for ManuallyDrop<T> { }
+unsafe impl<T: Pod> Pod for Wrapping<T> { }
+unsafe impl<T: Pod> Pod for Saturating<T> { }
 
-macro_rules! pod_def {
-    ($($x:expr),*) => {
+unsafe impl Zeroable for () { }
+unsafe impl Zeroable for f32 { }
+unsafe impl Zeroable for f64 { }
+unsafe impl Zeroable for i8 { }
+unsafe impl Zeroable for u8 { }
+unsafe impl Zeroable for i16 { }
+unsafe impl Zeroable for u16 { }
+unsafe impl Zeroable for i32 { }
+unsafe impl Zeroable for u32 { }
+unsafe impl Zeroable for i64 { }
+unsafe impl Zeroable for u64 { }
+unsafe impl Zeroable for i128 { }
+unsafe impl Zeroable for u128 { }
+unsafe impl Zeroable for isize { }
+unsafe impl Zeroable for usize { }
+unsafe impl<T> Zeroable for *const T { }
+unsafe impl<T> Zeroable for *mut T { }
+unsafe impl<T: ?Sized> Zeroable for PhantomData<T> { }
+unsafe impl<T: Zeroable> Zeroable for ManuallyDrop<T> { }
+unsafe impl<T: Zeroable> Zeroable for Wrapping<T> { }
+unsafe impl<T: Zeroable> Zeroable for Saturating<T> { }
+unsafe impl Zeroable for bool { }
+unsafe impl Zeroable for char { }
+unsafe impl<T: ?Sized> Zeroable for Option<NonNull<T>> { }
+unsafe impl<T: ?Sized> Zeroable for Option<&T> { }
+unsafe impl<T: ?Sized> Zeroable for Option<&mut T> { }
+#[cfg(feature = "alloc")]
+unsafe impl<T: ?Sized> Zeroable for Option<Box<T>> { }
+unsafe impl Zeroable for Option<NonZeroU8> { }
+unsafe impl Zeroable for Option<NonZeroU16> { }
+unsafe impl Zeroable for Option<NonZeroU32> { }
+unsafe impl Zeroable for Option<NonZeroU64> { }
+unsafe impl Zeroable for Option<NonZeroU128> { }
+unsafe impl Zeroable for Option<NonZeroUsize> { }
+unsafe impl Zeroable for Option<NonZeroI8> { }
+unsafe impl Zeroable for Option<NonZeroI16> { }
+unsafe impl Zeroable for Option<NonZeroI32> { }
+unsafe impl Zeroable for Option<NonZeroI64> { }
+unsafe impl Zeroable for Option<NonZeroI128> { }
+unsafe impl Zeroable for Option<NonZeroIsize> { }
+
+unsafe impl NoPadding for () { }
+unsafe impl NoPadding for f32 { }
+unsafe impl NoPadding for f64 { }
+unsafe impl NoPadding for i8 { }
+unsafe impl NoPadding for u8 { }
+unsafe impl NoPadding for i16 { }
+unsafe impl NoPadding for u16 { }
+unsafe impl NoPadding for i32 { }
+unsafe impl NoPadding for u32 { }
+unsafe impl NoPadding for i64 { }
+unsafe impl NoPadding for u64 { }
+unsafe impl NoPadding for i128 { }
+unsafe impl NoPadding for u128 { }
+unsafe impl NoPadding for isize { }
+unsafe impl NoPadding for usize { }
+unsafe impl<T> NoPadding for *const T { }
+unsafe impl<T> NoPadding for *mut T { }
+unsafe impl<T: ?Sized> NoPadding for PhantomData<T> { }
+unsafe impl<T: NoPadding> NoPadding for ManuallyDrop<T> {
+    #[doc(hidden)]
+    const __ASSERT_NO_PADDING: () = T::__ASSERT_NO_PADDING;
+}
+unsafe impl<T: NoPadding> NoPadding for Wrapping<T> {
+    #[doc(hidden)]
+    const __ASSERT_NO_PADDING: () = T::__ASSERT_NO_PADDING;
+}
+unsafe impl<T: NoPadding> NoPadding for Saturating<T> {
+    #[doc(hidden)]
+    const __ASSERT_NO_PADDING: () = T::__ASSERT_NO_PADDING;
+}
+
+unsafe impl<T: Zeroable> Zeroable for (T,) { }
+unsafe impl<T: Pod> Pod for (T,) { }
+unsafe impl<T: NoPadding> NoPadding for (T,) {
+    #[doc(hidden)]
+    const __ASSERT_NO_PADDING: () = T::__ASSERT_NO_PADDING;
+}
+
+unsafe impl<T: Zeroable, const N: usize> Zeroable for [T; N] { }
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] { }
+unsafe impl<T: NoPadding, const N: usize> NoPadding for [T; N] {
+    #[doc(hidden)]
+    const __ASSERT_NO_PADDING: () = T::__ASSERT_NO_PADDING;
+}
+
+impl<T: EndianConvert, const N: usize> EndianConvert for [T; N] {
+    #[inline]
+    fn swap_bytes(self) -> Self {
+        self.map(EndianConvert::swap_bytes)
+    }
+}
+
+impl<T: ?Sized> EndianConvert for PhantomData<T> {
+    #[inline]
+    fn swap_bytes(self) -> Self {
+        self
+    }
+}
+
+impl<T: EndianConvert> EndianConvert for ManuallyDrop<T> {
+    #[inline]
+    fn swap_bytes(self) -> Self {
+        ManuallyDrop::new(ManuallyDrop::into_inner(self).swap_bytes())
+    }
+}
+
+impl<T: EndianConvert> EndianConvert for Wrapping<T> {
+    #[inline]
+    fn swap_bytes(self) -> Self {
+        Wrapping(self.0.swap_bytes())
+    }
+}
+
+impl<T: EndianConvert> EndianConvert for Saturating<T> {
+    #[inline]
+    fn swap_bytes(self) -> Self {
+        Saturating(self.0.swap_bytes())
+    }
+}
+
+macro_rules! pod_simd {
+    ($($x:ident),*) => {
         $(
-            unsafe impl<T: Pod> Pod for [T; $x] { }
+            unsafe impl Zeroable for $x { }
+            unsafe impl Pod for $x { }
+            unsafe impl NoPadding for $x { }
         )*
     };
 }
 
-unsafe impl<T: Pod> Pod for (T,) { }
-pod_def! {
-    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
-    0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
-    0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2a, 0x2b, 0x2c, 0x2d, 0x2e, 0x2f,
-    0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x3a, 0x3b, 0x3c, 0x3d, 0x3e, 0x3f,
-    0x40,
-    0x100, 0x200, 0x300, 0x400, 0x500, 0x600, 0x700, 0x800, 0x900, 0xa00, 0xb00, 0xc00, 0xd00, 0xe00, 0xf00,
-    0x1000
+#[cfg(target_arch = "x86")]
+mod simd {
+    use core::arch::x86::*;
+    use super::{Zeroable, Pod, NoPadding};
+
+    pod_simd! { __m128, __m128d, __m128i, __m256, __m256d, __m256i }
 }
+
+#[cfg(target_arch = "x86_64")]
+mod simd {
+    use core::arch::x86_64::*;
+    use super::{Zeroable, Pod, NoPadding};
+
+    pod_simd! { __m128, __m128d, __m128i, __m256, __m256d, __m256i }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod simd {
+    use core::arch::aarch64::*;
+    use super::{Zeroable, Pod, NoPadding};
+
+    pod_simd! {
+        int8x8_t, int8x16_t, int16x4_t, int16x8_t, int32x2_t, int32x4_t, int64x1_t, int64x2_t,
+        uint8x8_t, uint8x16_t, uint16x4_t, uint16x8_t, uint32x2_t, uint32x4_t, uint64x1_t, uint64x2_t,
+        float32x2_t, float32x4_t, float64x1_t, float64x2_t,
+        poly8x8_t, poly8x16_t, poly16x4_t, poly16x8_t, poly64x1_t, poly64x2_t
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod simd {
+    use core::arch::wasm32::*;
+    use super::{Zeroable, Pod, NoPadding};
+
+    pod_simd! { v128 }
+}
+
+/// Compile-fail tests ensuring that padded types cannot be viewed as bytes
+/// through any conversion.
+///
+/// ```compile_fail,E0277
+/// # extern crate pod;
+/// # use pod::{Pod, NoPadding};
+/// # #[derive(Pod, Copy, Clone)]
+/// # #[repr(C)]
+/// # struct Padded { a: u8, b: u32 }
+/// # fn main() {
+/// # let padded = Padded { a: 0, b: 0 };
+/// padded.map::<[u8; 8]>();
+/// # }
+/// ```
+///
+/// ```compile_fail,E0277
+/// # extern crate pod;
+/// # use pod::{Pod, NoPadding};
+/// # #[derive(Pod, Copy, Clone)]
+/// # #[repr(C)]
+/// # struct Padded { a: u8, b: u32 }
+/// # fn main() {
+/// # let padded = Padded { a: 0, b: 0 };
+/// padded.map_copy::<[u8; 8]>();
+/// # }
+/// ```
+///
+/// ```compile_fail,E0277
+/// # extern crate pod;
+/// # use pod::{Pod, NoPadding};
+/// # #[derive(Pod, Copy, Clone)]
+/// # #[repr(C)]
+/// # struct Padded { a: u8, b: u32 }
+/// # fn main() {
+/// # let padded = Padded { a: 0, b: 0 };
+/// padded.try_map_copy::<[u8; 4]>();
+/// # }
+/// ```
+///
+/// ```compile_fail,E0277
+/// # extern crate pod;
+/// # use pod::{Pod, NoPadding};
+/// # #[derive(Pod, Copy, Clone)]
+/// # #[repr(C)]
+/// # struct Padded { a: u8, b: u32 }
+/// # fn main() {
+/// # let padded = Padded { a: 0, b: 0 };
+/// [0u8; 8].map_mut::<Padded>();
+/// # }
+/// ```
+///
+/// ```compile_fail,E0277
+/// # extern crate pod;
+/// # use pod::{Pod, NoPadding};
+/// # #[derive(Pod, Copy, Clone)]
+/// # #[repr(C)]
+/// # struct Padded { a: u8, b: u32 }
+/// # fn main() {
+/// # let padded = Padded { a: 0, b: 0 };
+/// padded.split::<u8>();
+/// # }
+/// ```
+///
+/// ```compile_fail,E0277
+/// # extern crate pod;
+/// # use pod::{Pod, NoPadding};
+/// # #[derive(Pod, Copy, Clone)]
+/// # #[repr(C)]
+/// # struct Padded { a: u8, b: u32 }
+/// # fn main() {
+/// # let padded = Padded { a: 0, b: 0 };
+/// padded.try_split::<u8>();
+/// # }
+/// ```
+///
+/// ```compile_fail,E0277
+/// # extern crate pod;
+/// # use pod::{Pod, NoPadding};
+/// # #[derive(Pod, Copy, Clone)]
+/// # #[repr(C)]
+/// # struct Padded { a: u8, b: u32 }
+/// # fn main() {
+/// # let padded = Padded { a: 0, b: 0 };
+/// padded.cast_split::<u8>();
+/// # }
+/// ```
+///
+/// ```compile_fail,E0277
+/// # extern crate pod;
+/// # use pod::{Pod, NoPadding};
+/// # #[derive(Pod, Copy, Clone)]
+/// # #[repr(C)]
+/// # struct Padded { a: u8, b: u32 }
+/// # fn main() {
+/// # let padded = Padded { a: 0, b: 0 };
+/// Padded::map_slice::<u8>(&[padded]);
+/// # }
+/// ```
+///
+/// ```compile_fail,E0277
+/// # extern crate pod;
+/// # use pod::{Pod, NoPadding};
+/// # #[derive(Pod, Copy, Clone)]
+/// # #[repr(C)]
+/// # struct Padded { a: u8, b: u32 }
+/// # fn main() {
+/// # let padded = Padded { a: 0, b: 0 };
+/// Padded::try_map_slice::<u8>(&[padded]);
+/// # }
+/// ```
+///
+/// ```compile_fail,E0277
+/// # extern crate pod;
+/// # use pod::{Pod, NoPadding};
+/// # #[derive(Pod, Copy, Clone)]
+/// # #[repr(C)]
+/// # struct Padded { a: u8, b: u32 }
+/// # fn main() {
+/// # let padded = Padded { a: 0, b: 0 };
+/// Padded::cast_slice::<u8>(&[padded]);
+/// # }
+/// ```
+///
+/// ```compile_fail,E0277
+/// # extern crate pod;
+/// # use pod::{Pod, NoPadding};
+/// # #[derive(Pod, Copy, Clone)]
+/// # #[repr(C)]
+/// # struct Padded { a: u8, b: u32 }
+/// # fn main() {
+/// # let padded = Padded { a: 0, b: 0 };
+/// Padded::merge_copy::<[u8; 8]>(&[padded]);
+/// # }
+/// ```
+///
+/// ```compile_fail,E0277
+/// # extern crate pod;
+/// # use pod::{Pod, NoPadding};
+/// # #[derive(Pod, Copy, Clone)]
+/// # #[repr(C)]
+/// # struct Padded { a: u8, b: u32 }
+/// # fn main() {
+/// # let padded = Padded { a: 0, b: 0 };
+/// Padded::try_merge_copy::<u8>(&[padded]);
+/// # }
+/// ```
+///
+/// ```compile_fail,E0277
+/// # extern crate pod;
+/// # use pod::{Pod, NoPadding};
+/// # #[derive(Pod, Copy, Clone)]
+/// # #[repr(C)]
+/// # struct Padded { a: u8, b: u32 }
+/// # fn main() {
+/// # let padded = Padded { a: 0, b: 0 };
+/// <[u8; 8]>::from_ref(&padded);
+/// # }
+/// ```
+///
+/// ```compile_fail,E0277
+/// # extern crate pod;
+/// # use pod::{Pod, NoPadding};
+/// # #[derive(Pod, Copy, Clone)]
+/// # #[repr(C)]
+/// # struct Padded { a: u8, b: u32 }
+/// # fn main() {
+/// # let padded = Padded { a: 0, b: 0 };
+/// Padded::ref_from_bytes_mut(&mut [0u8; 8]);
+/// # }
+/// ```
+///
+/// ```compile_fail,E0277
+/// # extern crate pod;
+/// # use pod::{Pod, NoPadding};
+/// # #[derive(Pod, Copy, Clone)]
+/// # #[repr(C)]
+/// # struct Padded { a: u8, b: u32 }
+/// # fn main() {
+/// # let padded = Padded { a: 0, b: 0 };
+/// pod::PodCursorMut::new(&mut [0u8; 8]).ref_pod_mut::<Padded>();
+/// # }
+/// ```
+///
+/// ```compile_fail,E0080
+/// # extern crate pod;
+/// # use pod::{Pod, NoPadding};
+/// # #[derive(Pod, NoPadding, Copy, Clone)]
+/// # #[repr(C)]
+/// # struct G<T>(u8, T);
+/// # fn main() -> Result<(), pod::CastError> {
+/// let mut words = [0u32; 2];
+/// let mut cursor = pod::PodCursorMut::new(pod::Pod::as_bytes_mut(&mut words));
+/// *cursor.ref_pod_mut::<G<u32>>()? = G(1, 2);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// ```compile_fail,E0080
+/// # extern crate pod;
+/// # use pod::{Pod, NoPadding};
+/// # #[derive(Pod, NoPadding, Copy, Clone)]
+/// # #[repr(C)]
+/// # struct G<T>(u8, T);
+/// # fn main() -> Result<(), pod::CastError> {
+/// let mut words = [0u32; 2];
+/// let mut cursor = pod::PodCursorMut::new(pod::Pod::as_bytes_mut(&mut words));
+/// cursor.ref_pod_slice_mut::<G<u32>>(1)?[0] = G(1, 2);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// ```compile_fail,E0080
+/// # extern crate pod;
+/// # use pod::{Pod, NoPadding};
+/// # #[derive(Pod, NoPadding, Copy, Clone)]
+/// # #[repr(C)]
+/// # struct G<T>(u8, T);
+/// # fn main() -> Result<(), pod::CastError> {
+/// let mut words = [0u32; 2];
+/// let mut writer = pod::PodSliceWriter::new(pod::Pod::as_bytes_mut(&mut words));
+/// *writer.reserve::<G<u32>>()? = G(1, 2);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Generic types only learn whether they are padded once instantiated, which
+/// must be checked through arrays, tuples and other derived types as well.
+///
+/// ```compile_fail,E0080
+/// # extern crate pod;
+/// # use pod::{Pod, NoPadding};
+/// # #[derive(Pod, NoPadding, Copy, Clone)]
+/// # #[repr(C)]
+/// # struct G<T>(u8, T);
+/// # fn main() {
+/// [G(1u8, 2u32)].as_bytes();
+/// # }
+/// ```
+///
+/// ```compile_fail,E0080
+/// # extern crate pod;
+/// # use pod::{Pod, NoPadding};
+/// # #[derive(Pod, NoPadding, Copy, Clone)]
+/// # #[repr(C)]
+/// # struct G<T>(u8, T);
+/// #[derive(Pod, NoPadding)]
+/// #[repr(C)]
+/// struct Outer { g: G<u32> }
+/// # fn main() {
+/// # Outer { g: G(1, 2) }.as_bytes();
+/// # }
+/// ```
+#[cfg(all(doctest, feature = "derive"))]
+mod padding_tests { }
+
+/// Compile-fail tests ensuring that padded types cannot be moved into byte
+/// allocations or written to a stream.
+///
+/// ```compile_fail,E0277
+/// # extern crate pod;
+/// # use pod::{Pod, NoPadding};
+/// # #[derive(Pod, Copy, Clone)]
+/// # #[repr(C)]
+/// # struct Padded { a: u8, b: u32 }
+/// # fn main() {
+/// # let padded = Padded { a: 0, b: 0 };
+/// Box::new(padded).split_box::<u8>();
+/// # }
+/// ```
+///
+/// ```compile_fail,E0080
+/// # extern crate pod;
+/// # use pod::{Pod, NoPadding};
+/// # use pod::PodWriteExt;
+/// # #[derive(Pod, NoPadding, Copy, Clone)]
+/// # #[repr(C)]
+/// # struct G<T>(u8, T);
+/// # fn main() {
+/// Vec::new().write_pod(&(G(1u8, 2u32),)).unwrap();
+/// # }
+/// ```
+#[cfg(all(doctest, feature = "std", feature = "derive"))]
+mod std_padding_tests { }
@@ -0,0 +1,59 @@
+extern crate pod;
+
+use pod::{Pod, CastError, PodCursor, PodCursorMut};
+
+#[test]
+fn test_cursor() {
+    let words = [0x0102u16.to_be(), 3, 4, 5];
+    let bytes = words.as_bytes();
+
+    let mut cursor = PodCursor::new(&bytes[1..]);
+    assert!(cursor.ref_pod::<u16>() == Err(CastError::Alignment { align: 2, offset: 1 }));
+    assert!(cursor.read_pod::<u8>() == Ok(2));
+    assert!(cursor.ref_pod::<u16>() == Ok(&3));
+    assert!(cursor.position() == 3 && cursor.remaining().len() == 4);
+    assert!(cursor.ref_pod_slice::<u16>(2) == Ok(&[4, 5][..]));
+    assert!(cursor.read_pod::<u8>() == Err(CastError::Size { expected: 1, actual: 0 }));
+
+    let aligned = [0u32; 2];
+    let mut cursor = PodCursor::new(aligned.as_bytes());
+    cursor.skip(1).unwrap();
+    cursor.align_to::<u32>().unwrap();
+    assert!(cursor.position() == 4);
+    assert!(cursor.skip(5).is_err() && cursor.remaining().len() == 4);
+}
+
+#[test]
+fn test_cursor_mut() {
+    let mut words = [0u32; 3];
+
+    let mut cursor = PodCursorMut::new(words.as_bytes_mut());
+    cursor.write_pod(&1u32).unwrap();
+    *cursor.ref_pod_mut::<u32>().unwrap() = 2;
+    cursor.ref_pod_slice_mut::<u16>(2).unwrap()[1] = 0xffff;
+    assert!(cursor.write_pod(&0u8).is_err() && cursor.position() == 12);
+
+    assert!(words[..2] == [1, 2] && words[2] != 0);
+    assert!(PodCursorMut::new(words.as_bytes_mut()).read_pod::<u32>() == Ok(1));
+}
+
+#[test]
+fn test_slice_writer() {
+    use pod::PodSliceWriter;
+
+    let mut words = [u32::MAX; 4];
+    let mut writer = PodSliceWriter::new(words.as_bytes_mut());
+    writer.write_pod(&1u8).unwrap();
+    writer.pad_to_align::<u32>().unwrap();
+    *writer.reserve::<u32>().unwrap() = 2;
+    writer.write_pod_slice(&[3u16, 4]).unwrap();
+    assert!(writer.position() == 12 && writer.remaining() == 4);
+    assert!(writer.reserve::<u64>() == Err(CastError::Size { expected: 8, actual: 4 }));
+    writer.write_pod(&0u8).unwrap();
+    assert!(writer.reserve::<u16>() == Err(CastError::Alignment { align: 2, offset: 1 }));
+    assert!(writer.written().len() == 13);
+
+    assert!(words[0].as_bytes() == [1, 0, 0, 0]);
+    assert!(words[1] == 2 && words[2].as_bytes() == [3u16, 4].as_bytes());
+    assert!(words[3].as_bytes()[1..] == [0xff; 3]);
+}
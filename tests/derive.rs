@@ -0,0 +1,100 @@
+#![cfg(feature = "derive")]
+
+extern crate pod;
+
+use pod::{Pod, Zeroable, NoPadding, CheckedPod, CheckedError};
+
+#[derive(Pod)]
+#[repr(C)]
+struct Header {
+    magic: [u8; 4],
+    len: u32,
+}
+
+#[derive(Pod, NoPadding)]
+#[repr(C, packed)]
+struct Packed(u8, u32);
+
+#[derive(Pod, NoPadding)]
+#[repr(transparent)]
+struct Wrapper<T>(T);
+
+#[derive(Pod, NoPadding)]
+#[repr(C)]
+struct Pair<T>(T, T);
+
+#[test]
+fn test_derive() {
+    let header = Header::from_bytes(b"POD\0\x04\0\0\0").unwrap();
+    assert!(&header.magic == b"POD\0");
+    assert!(header.len == u32::from_le(4));
+
+    let packed = Packed::zeroed();
+    assert!(packed.as_bytes() == [0; 5]);
+
+    let wrapper = Wrapper(0xffu16);
+    assert!(wrapper.map::<Wrapper<i16>>().unwrap().0 == 0xff);
+    assert!(wrapper.as_bytes() == 0xffu16.as_bytes());
+
+    let pair = Pair(1u8, 2u8);
+    assert!(pair.as_bytes() == [1, 2]);
+    assert!([Wrapper(pair)].as_bytes() == [1, 2] && (Pair(3u16, 4),).as_bytes().len() == 4);
+}
+
+#[derive(Copy, Clone, PartialEq, Debug, CheckedPod)]
+#[repr(u8)]
+enum Kind {
+    File = 1,
+    Directory = 2,
+}
+
+#[derive(CheckedPod)]
+#[repr(C)]
+struct Entry {
+    kind: Kind,
+    hidden: bool,
+    id: u16,
+}
+
+#[test]
+fn test_derive_checked() {
+    assert!(Kind::from_bytes_checked(&[2]) == Ok(Kind::Directory));
+    assert!(Kind::from_bytes_checked(&[0]) == Err(CheckedError::Invalid));
+    assert!(Kind::from_bytes_checked(&[1, 0]) == Err(CheckedError::Size));
+
+    let entry = Entry::from_bytes_checked(&[1, 1, 0, 0]).unwrap();
+    assert!(entry.kind == Kind::File && entry.hidden && entry.id == 0);
+    assert!(Entry::from_bytes_checked(&[1, 2, 0, 0]).is_err());
+    assert!(Entry::from_bytes_checked(&[3, 1, 0, 0]).is_err());
+}
+
+#[derive(Copy, Clone, PartialEq, Debug, Zeroable)]
+#[repr(u16)]
+enum State {
+    Idle = 0,
+    Busy,
+}
+
+#[derive(Zeroable)]
+struct Slot<'a, T> {
+    state: State,
+    next: Option<&'a T>,
+    len: Option<std::num::NonZeroU32>,
+}
+
+#[test]
+fn test_derive_zeroable() {
+    let slot = Slot::<u8>::zeroed();
+    assert!(slot.state == State::Idle && slot.next.is_none() && slot.len.is_none());
+    // Parameters only used behind references need not be `Zeroable`
+    assert!(Slot::<String>::zeroed().next.is_none());
+
+    assert!(State::Busy != State::Idle);
+
+    #[cfg(feature = "alloc")]
+    {
+        let slots = Slot::<u8>::zeroed_vec(0x100);
+        assert!(slots.len() == 0x100 && slots.iter().all(|s| s.state == State::Idle));
+        assert!(State::zeroed_box() == Box::new(State::Idle));
+    }
+}
@@ -0,0 +1,62 @@
+#![cfg(all(feature = "derive", feature = "std"))]
+
+extern crate pod;
+
+use std::mem::{align_of, size_of};
+use pod::{Pod, NoPadding, EndianConvert};
+use pod::endian::{Le, Be, U16Be, U32Le, F32Be};
+
+#[derive(Pod, NoPadding)]
+#[repr(C)]
+struct Header {
+    magic: [u8; 3],
+    version: U16Be,
+    len: U32Le,
+    scale: F32Be,
+}
+
+#[test]
+fn test_endian() {
+    assert!(size_of::<Header>() == 13 && align_of::<Header>() == 1);
+
+    let mut header = Header::from_bytes(b"pod\x00\x05\x10\x00\x00\x00\x3f\x80\x00\x00").unwrap();
+    assert!(header.version.get() == 5);
+    assert!(u32::from(header.len) == 0x10);
+    assert!(header.scale.get() == 1.0);
+
+    header.len.set(0x01020304);
+    assert!(header.len.as_bytes() == [4, 3, 2, 1]);
+    assert!(Be::new(0x01020304u32).as_bytes() == [1, 2, 3, 4]);
+    assert!(Le::from(-2i16) == Le::new(-2));
+}
+
+#[derive(Pod, NoPadding, EndianConvert)]
+#[repr(C, packed)]
+struct Record {
+    id: u32,
+    kind: u8,
+    offsets: [i16; 2],
+    crc: U16Be,
+}
+
+#[test]
+fn test_endian_convert() {
+    use pod::{PodReadExt, PodWriteExt};
+
+    assert!(0x0102u16.to_be() == u16::from_be(0x0102));
+    assert!(EndianConvert::swap_bytes(1.0f32).swap_bytes() == 1.0);
+
+    let data = [0, 0, 0, 1, 7, 0xff, 0xfe, 0, 2, 0, 3];
+    let record = (&data[..]).read_pod_be::<Record>().unwrap();
+    let id = record.id;
+    let offsets = record.offsets;
+    assert!(id == 1 && record.kind == 7 && offsets == [-2, 2]);
+    assert!(record.crc.get() == 3);
+
+    let mut out = Vec::new();
+    out.write_pod_le(&record).unwrap();
+    assert!(out == [1, 0, 0, 0, 7, 0xfe, 0xff, 2, 0, 0, 3]);
+    out.clear();
+    out.write_pod_be(&record).unwrap();
+    assert!(out == data);
+}
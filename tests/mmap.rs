@@ -0,0 +1,41 @@
+#![cfg(feature = "mmap")]
+
+extern crate pod;
+
+use std::fs::{self, OpenOptions};
+use std::io::ErrorKind;
+use pod::PodWriteExt;
+use pod::mmap::{PodMmap, PodMmapMut, PodRecords, PodRecordsMut};
+
+#[test]
+fn test_mmap() {
+    let path = std::env::temp_dir().join(format!("pod-test-mmap-{}", std::process::id()));
+    let mut file = OpenOptions::new().read(true).write(true).create(true).truncate(true).open(&path).unwrap();
+    file.write_pod(&[2u32, 0]).unwrap();
+    file.write_pod_slice(&[10u64, 20]).unwrap();
+
+    {
+        let mut map = unsafe { PodMmapMut::<[u32; 2], u64>::map_mut(&file).unwrap() };
+        assert!(*map.header() == [2, 0] && map[..] == [10, 20]);
+        map[1] = 21;
+        map.extend_from_slice(&[30]).unwrap();
+        map.header_mut()[0] = 3;
+        map.flush_range(1..3).unwrap();
+        map.flush().unwrap();
+    }
+
+    let map = unsafe { PodMmap::<[u32; 2], u64>::map(&file).unwrap() };
+    assert!(map.header()[0] == 3 && map[..] == [10, 21, 30]);
+    assert!(unsafe { PodRecords::<u64>::map(&file).unwrap().len() == 4 });
+    assert!(unsafe { PodRecords::<[u8; 3]>::map(&file).unwrap_err().kind() == ErrorKind::InvalidData });
+    assert!(unsafe { PodMmap::<u32, u64>::map(&file).unwrap_err().kind() == ErrorKind::InvalidData });
+
+    drop(map);
+    {
+        let mut records = unsafe { PodRecordsMut::<u64>::map_mut(&file).unwrap() };
+        records.extend_from_slice(&[40]).unwrap();
+        assert!(records.len() == 5 && records[1..] == [10, 21, 30, 40]);
+    }
+
+    fs::remove_file(&path).unwrap();
+}
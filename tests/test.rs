@@ -25,25 +25,428 @@ fn test_map() {
     assert!(0u16.map_copy::<u8>().is_none());
     assert!(0u16.try_map::<u8>().unwrap() == &0);
     assert!(0u16.try_map_mut::<u8>().unwrap() == &mut 0);
-    assert!(Pod::map_box::<u8>(Box::new(0u16)).is_err());
 
     assert!(0u16.map::<u32>().is_none());
     assert!(0u16.map_mut::<u32>().is_none());
     assert!(0u16.map_copy::<u32>().is_none());
     assert!(0u16.try_map::<u32>().is_none());
     assert!(0u16.try_map_mut::<u32>().is_none());
-    assert!(Pod::map_box::<u32>(Box::new(0u16)).is_err());
 
     assert!(0xffu16.map::<i16>().unwrap() == &0xffi16);
     assert!(0xffu16.map_mut::<i16>().unwrap() == &mut 0xffi16);
     assert!(0xffu16.map_copy::<i16>().unwrap() == 0xffi16);
     assert!(0xffu16.try_map::<i16>().unwrap() == &0xff);
     assert!(0xffu16.try_map_mut::<i16>().unwrap() == &mut 0xff);
+
+    assert!(Pod::map::<i16>(&*un).is_none());
+    assert!(Pod::map_mut::<i16>(un).is_none());
+    assert!(Pod::map_copy::<i16>(&*un).unwrap() == 0x0101i16);
+    assert!(Pod::try_map::<i16>(&*un).is_none());
+    assert!(Pod::try_map_mut::<i16>(un).is_none());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_map_box() {
+    assert!(Pod::map_box::<u8>(Box::new(0u16)).is_err());
+    assert!(Pod::map_box::<u32>(Box::new(0u16)).is_err());
     assert!(*Pod::map_box::<i16>(Box::new(0xffu16)).unwrap() == 0xff);
+}
+
+#[test]
+fn test_merge_copy_bounds() {
+    // Slices shorter than the output type used to be read past their end
+    assert!(u8::try_merge_copy::<u32>(&[1, 2]).is_none());
+    assert!(u8::try_merge_copy::<u16>(&[1, 2, 3]) == Some(u16::from_ne_bytes([1, 2])));
+}
+
+#[test]
+fn test_checked() {
+    use pod::{CheckedPod, CheckedError};
+
+    assert!(bool::from_bytes_checked(&[1]) == Ok(true));
+    assert!(bool::from_bytes_checked(&[2]) == Err(CheckedError::Invalid));
+    assert!(char::from_bytes_checked(0x61u32.as_bytes()) == Ok('a'));
+    assert!(char::from_bytes_checked(0xd800u32.as_bytes()) == Err(CheckedError::Invalid));
+    assert!(<Option<std::num::NonZeroU32>>::from_bytes_checked(&[0; 4]) == Ok(None));
+    assert!(u16::ref_from_bytes_checked(&[0; 3]) == Err(CheckedError::Size));
+
+    #[cfg(feature = "std")]
+    {
+        use pod::PodReadExt;
+
+        let mut data = std::io::Cursor::new([1, 3]);
+        assert!(data.read_pod_checked::<bool>().unwrap());
+        assert!(data.read_pod_checked::<bool>().unwrap_err().kind() == std::io::ErrorKind::InvalidData);
+    }
+}
+
+#[test]
+fn test_arrays() {
+    let bytes = [7u8; 100];
+    assert!(<[u8; 100]>::from_bytes(&bytes).unwrap()[..] == bytes[..]);
+
+    #[cfg(feature = "alloc")]
+    {
+        use pod::Zeroable;
+
+        let large = <[[f32; 3]; 1000]>::zeroed_box();
+        assert!(large.split::<f32>().unwrap().len() == 3000);
+        assert!(<[u32; 4097]>::zeroed_vec(1)[0].as_bytes().len() == 4097 * 4);
+        assert!(<()>::zeroed_vec(usize::MAX).len() == usize::MAX && <[u8; 0]>::zeroed_vec(0).is_empty());
+    }
+}
+
+#[test]
+fn test_std_types() {
+    use std::marker::PhantomData;
+    use std::mem::ManuallyDrop;
+    use std::num::Wrapping;
+    use pod::EndianConvert;
+
+    let hash = u128::from_bytes(&[0xff; 16]).unwrap();
+    assert!(hash == u128::MAX && hash.map::<[i128; 1]>().unwrap()[0] == -1);
+    assert!(EndianConvert::swap_bytes(Wrapping(0x0102u16)) == Wrapping(0x0201));
+    assert!(*ManuallyDrop::new(5u32).as_bytes() == *5u32.as_bytes());
+    assert!(PhantomData::<String>.as_bytes().is_empty());
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        use std::arch::x86_64::__m128i;
+        assert!(__m128i::from_bytes(&[0; 16]).unwrap().as_bytes() == [0; 16]);
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_read() {
+    use pod::PodReadExt;
+    use std::io::ErrorKind;
+
+    let mut data = &[1, 0, 2, 0, 3][..];
+    assert!(u16::from_le(data.read_pod().unwrap()) == 1);
+    assert!(u16::from_le(data.read_pod().unwrap()) == 2);
+    assert!(data.read_pod::<u16>().unwrap_err().kind() == ErrorKind::UnexpectedEof);
+
+    let mut value = u32::uninit();
+    for byte in pod::as_uninit_bytes_mut(&mut value) {
+        *byte = std::mem::MaybeUninit::new(0xff);
+    }
+    assert!(unsafe { value.assume_init() } == u32::MAX);
+}
+
+#[test]
+#[cfg(feature = "read_exact")]
+fn test_read_or_none() {
+    use pod::PodReadExt;
+    use std::io::ErrorKind;
+
+    let mut data = &[1, 0, 2, 0, 3][..];
+    assert!(u16::from_le(data.read_pod().unwrap()) == 1);
+    assert!(data.read_pod_or_none::<u16>().unwrap() == Some(u16::from_le(2)));
+    assert!(data.read_pod_or_none::<u16>().unwrap_err().kind() == ErrorKind::UnexpectedEof);
+    assert!(data.read_pod_or_none::<u16>().unwrap().is_none());
+    assert!(data.read_pod::<u16>().unwrap_err().kind() == ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn test_cast_error() {
+    use pod::CastError;
+
+    let words = [0u32; 4];
+    assert!(u32::cast_ref_from_bytes(&[0; 3]) == Err(CastError::Size { expected: 4, actual: 3 }));
+    assert!(<u8>::cast_slice::<u32>(&[0; 6]) == Err(CastError::Remainder { size: 4, actual: 6 }));
+    assert!(<u8>::cast_slice::<u32>(&words.as_bytes()[1..5]) == Err(CastError::Alignment { align: 4, offset: 1 }));
+    assert!(words.cast_split::<u16>().map(|s| s.len()) == Ok(8));
+    assert!(u16::cast_from_bytes(&[1, 0]) == Ok(u16::from_le(1)));
+    assert!(CastError::Size { expected: 8, actual: 7 }.to_string() == "expected 8 bytes, found 7");
+
+    #[cfg(feature = "alloc")]
+    {
+        let (err, bytes) = u64::cast_from_byte_vec(vec![0; 7]).unwrap_err();
+        assert!(err == CastError::Size { expected: 8, actual: 7 } && bytes.len() == 7);
+
+        let boxed = Box::new([1u8, 2]);
+        let (_, boxed) = boxed.cast_box::<u32>().unwrap_err();
+        assert!(*boxed == [1, 2]);
+    }
+}
+
+#[test]
+fn test_prefix() {
+    let mut words = [0x0102u16, 0x0304, 0x0506];
+    let bytes = words.as_bytes();
+
+    let (head, rest) = u16::ref_from_prefix(bytes).unwrap();
+    assert!(*head == 0x0102 && rest.len() == 4);
+    let (rest, tail) = u16::ref_from_suffix(bytes).unwrap();
+    assert!(*tail == 0x0506 && rest.len() == 4);
+    assert!(u16::ref_from_prefix(&bytes[1..]).is_none());
+    assert!(u32::ref_from_suffix(&bytes[..3]).is_none());
+
+    let (value, rest) = u16::from_prefix(&bytes[2..]).unwrap();
+    assert!(value == 0x0304 && rest.len() == 2);
+    let (rest, value) = u16::from_suffix(&bytes[..5]).unwrap();
+    assert!(value.to_ne_bytes() == [bytes[3], bytes[4]] && rest.len() == 3);
+    assert!(u64::from_prefix(bytes).is_none());
+    assert!(u16::try_merge_copy::<u32>(&[0; 3]).is_some() && u16::try_merge_copy::<u64>(&[0; 3]).is_none());
+
+    let (head, _) = u16::ref_from_prefix_mut(words.as_bytes_mut()).unwrap();
+    *head = 7;
+    let (_, tail) = u16::ref_from_suffix_mut(words.as_bytes_mut()).unwrap();
+    *tail = 9;
+    assert!(words == [7, 0x0304, 9]);
+}
+
+/// A reader that returns at most `chunk` bytes per call.
+#[cfg(feature = "std")]
+struct ShortReader<'a> {
+    data: &'a [u8],
+    chunk: usize,
+}
+
+#[cfg(feature = "std")]
+impl<'a> std::io::Read for ShortReader<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let len = buf.len().min(self.chunk);
+        std::io::Read::read(&mut self.data, &mut buf[..len])
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_read_slice() {
+    use pod::{PodReadExt, PodWriteExt};
+    use std::io::ErrorKind;
+
+    let mut bytes = Vec::new();
+    bytes.write_pod_slice(&[1u32, 2, 3]).unwrap();
+    assert!(bytes.len() == 12);
+
+    let mut data = &bytes[..];
+    let mut first = [0u32; 1];
+    data.read_pod_slice(&mut first).unwrap();
+    assert!(first == [1] && data.read_pod_vec::<u32>(2).unwrap() == [2, 3]);
+    assert!((&bytes[..8]).read_pod_vec::<u32>(3).unwrap_err().kind() == ErrorKind::UnexpectedEof);
+
+    let records = (0..1000u16).collect::<Vec<_>>();
+    let mut data = u16::slice_as_bytes(&records);
+    assert!(data.read_to_end_pod::<u16>().unwrap() == records);
+    assert!((&bytes[..11]).read_to_end_pod::<u32>().unwrap_err().kind() == ErrorKind::UnexpectedEof);
+    assert!((&[][..]).read_to_end_pod::<u64>().unwrap().is_empty());
+
+    let records = (0..100_000u32).collect::<Vec<_>>();
+    let mut short = ShortReader { data: u32::slice_as_bytes(&records), chunk: 4093 };
+    assert!(short.read_to_end_pod::<u32>().unwrap() == records);
+    let mut short = ShortReader { data: &bytes[..11], chunk: 3 };
+    assert!(short.read_to_end_pod::<u32>().unwrap_err().kind() == ErrorKind::UnexpectedEof);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_read_limited() {
+    use pod::{PodReadExt, LimitExceeded};
+    use std::io::ErrorKind;
+
+    let mut bytes = 3u32.as_bytes().to_vec();
+    bytes.extend_from_slice(u16::slice_as_bytes(&[7, 8, 9]));
+    assert!((&bytes[..]).read_len_prefixed::<u32, u16>(6).unwrap() == [7, 8, 9]);
+
+    let err = (&bytes[..]).read_len_prefixed::<u32, u16>(5).unwrap_err();
+    assert!(err.kind() == ErrorKind::InvalidData);
+    assert!(*err.get_ref().unwrap().downcast_ref::<LimitExceeded>().unwrap() == LimitExceeded { requested: 6, limit: 5 });
+
+    let huge = u32::MAX.as_bytes();
+    assert!((&huge[..]).read_len_prefixed::<u32, u64>(usize::MAX).unwrap_err().kind() == ErrorKind::UnexpectedEof);
+    assert!((&bytes[4..]).read_pod_vec_limited::<u16>(4, 8).unwrap_err().kind() == ErrorKind::UnexpectedEof);
+
+    let records = (0..100_000u32).collect::<Vec<_>>();
+    let data = u32::slice_as_bytes(&records).read_pod_vec_limited::<u32>(records.len(), usize::MAX).unwrap();
+    assert!(data == records && data.capacity() == records.len());
+    assert!((&[][..]).read_pod_vec_limited::<()>(usize::MAX, 0).unwrap().len() == usize::MAX);
+}
+
+#[test]
+#[cfg(feature = "read_exact")]
+fn test_pod_iter() {
+    use pod::PodReadExt;
+    use std::io::{BufReader, Cursor, ErrorKind};
+
+    let bytes = u16::slice_as_bytes(&[1, 2, 3]).to_vec();
+    let records = BufReader::new(&bytes[..]).pod_iter::<u16>().collect::<Result<Vec<_>, _>>().unwrap();
+    assert!(records == [1, 2, 3]);
+
+    let mut iter = Cursor::new(&bytes[..5]).pod_iter::<u16>().with_size_hint().unwrap();
+    assert!(iter.size_hint() == (2, Some(3)));
+    assert!(iter.next().unwrap().unwrap() == 1 && iter.size_hint() == (1, Some(2)));
+    assert!(iter.next().unwrap().unwrap() == 2);
+    assert!(iter.next().unwrap().unwrap_err().kind() == ErrorKind::UnexpectedEof);
+    assert!(iter.next().is_none() && iter.size_hint() == (0, Some(0)));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_peek() {
+    use pod::{PodReadExt, PodBufReadExt, BufferTooShort};
+    use std::io::{BufReader, ErrorKind};
+
+    let bytes = u32::slice_as_bytes(&[1, 2, 3]).to_vec();
+    let mut data = BufReader::with_capacity(6, &bytes[..]);
+    assert!(data.peek_pod::<u32>().unwrap() == Some(1));
+    assert!(data.with_pod_ref(|&v: &u32| v + 1).unwrap() == Some(2));
+    data.consume_pod::<u32>().unwrap();
+
+    // The second value straddles the end of the buffer
+    let err = data.peek_pod::<u32>().unwrap_err();
+    assert!(err.kind() == ErrorKind::Other);
+    assert!(*err.get_ref().unwrap().downcast_ref::<BufferTooShort>().unwrap() == BufferTooShort { buffered: 2, required: 4 });
+    assert!(data.with_pod_ref(|_: &u32| ()).is_err());
+    data.consume_pod::<u32>().unwrap();
+    assert!(data.peek_pod::<u32>().unwrap() == Some(3));
+    assert!(data.read_pod::<u32>().unwrap() == 3);
+    assert!(data.peek_pod::<u8>().unwrap().is_none());
+    assert!(data.consume_pod::<u8>().is_err());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_read_at() {
+    use pod::{PodReadAtExt, PodWriteAtExt};
+    use std::fs::{self, OpenOptions};
+    use std::io::Cursor;
+    use std::sync::Mutex;
+
+    let path = std::env::temp_dir().join(format!("pod-test-read-at-{}", std::process::id()));
+    let file = OpenOptions::new().read(true).write(true).create(true).truncate(true).open(&path).unwrap();
+
+    for i in 0..4u64 {
+        file.write_record(i, &(i * 10)).unwrap();
+    }
+    file.write_pod_at(32, &0xffu8).unwrap();
+    assert!(file.record_count::<u64>().unwrap() == 4 && file.record_count::<()>().unwrap() == 0);
+    assert!(file.read_record::<u64>(2).unwrap() == 20);
+    assert!(file.read_pod_at::<u8>(32).unwrap() == 0xff);
+    assert!(file.read_record::<u64>(4).is_err());
+    assert!(file.read_record::<u64>(u64::MAX).unwrap_err().kind() == std::io::ErrorKind::InvalidInput);
+
+    drop(file);
+    fs::remove_file(&path).unwrap();
+
+    let stream = Mutex::new(Cursor::new(Vec::new()));
+    stream.write_record(1, &7u32).unwrap();
+    stream.write_pod_at(9, &0xffu8).unwrap();
+    assert!(stream.record_count::<u32>().unwrap() == 2);
+    assert!(stream.read_record::<u32>(0).unwrap() == 0 && stream.read_record::<u32>(1).unwrap() == 7);
+    assert!(stream.read_pod_at::<u8>(9).unwrap() == 0xff);
+    assert!(stream.read_record::<u32>(2).unwrap_err().kind() == std::io::ErrorKind::UnexpectedEof);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_vectored() {
+    use pod::{PodReadExt, PodWriteExt};
+
+    let mut bytes = Vec::new();
+    bytes.write_pods_vectored(&[&1u8, &[2u16, 3], &[0u8; 0], &4u32]).unwrap();
+    assert!(bytes.len() == 9);
+
+    let (mut a, mut b, mut c) = (0u8, [0u16; 2], 0u32);
+    (&bytes[..]).read_pods_vectored(&mut [&mut a, &mut b, &mut c]).unwrap();
+    assert!(a == 1 && b == [2, 3] && c == 4);
+
+    // A chained reader splits the read across several calls
+    std::io::Read::chain(&bytes[..2], &bytes[2..]).read_pods_vectored(&mut [&mut c, &mut b, &mut a]).unwrap();
+    assert!(c.as_bytes() == &bytes[..4] && a == bytes[8]);
+    let mut data = std::io::Read::chain(&bytes[..2], &bytes[2..8]);
+    assert!(data.read_pods_vectored(&mut [&mut a, &mut b, &mut c]).unwrap_err().kind() == std::io::ErrorKind::UnexpectedEof);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_box_layout() {
+    use pod::CastError;
+
+    let bytes = vec![1u8; 8].into_boxed_slice();
+    let (err, bytes) = u8::cast_slice_box::<u64>(bytes).unwrap_err();
+    assert!(err == CastError::Layout { align: std::mem::align_of::<u64>(), actual: 1 });
+    assert!(u8::realloc_cast::<u64>(bytes).unwrap()[..] == [u64::from_ne_bytes([1; 8])]);
+    assert!(u8::realloc_cast::<u64>(vec![0; 7].into_boxed_slice()).is_err());
+    assert!(u16::realloc_cast::<i16>(vec![7u16].into_boxed_slice()).unwrap()[..] == [7]);
+
+    assert!(*u64::from_byte_vec(vec![0; 8]).unwrap() == 0);
+    assert!(Box::new(5u32).into_byte_vec() == 5u32.as_bytes());
+    assert!(Pod::map_box::<[u8; 4]>(Box::new(0u32)).is_err());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn test_cast_vec() {
+    use pod::CastError;
+
+    let mut words = Vec::<u32>::with_capacity(8);
+    words.extend_from_slice(&[1, 2]);
+    let capacity = words.capacity();
+    let pairs = u32::cast_vec::<[u32; 2]>(words).unwrap();
+    assert!(pairs == [[1, 2]] && pairs.capacity() * 2 == capacity);
+    assert!(<[u32; 2]>::cast_vec::<i32>(pairs).unwrap().capacity() == capacity);
+
+    // A boxed slice converts to a vector with exactly its length as capacity
+    let mut odd = vec![1u16, 2, 3].into_boxed_slice().into_vec();
+    odd.truncate(2);
+    let (err, odd) = u16::cast_vec::<[u16; 2]>(odd).unwrap_err();
+    assert!(err == CastError::Remainder { size: 4, actual: 6 } && odd == [1, 2]);
+    assert!(u8::cast_vec::<u32>(vec![0; 4]).unwrap_err().0 == CastError::Layout { align: 4, actual: 1 });
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_aligned_buf() {
+    use pod::{AlignedBuf, CastError, PodReadExt, PodWriteExt};
+    use std::mem::align_of;
+
+    let mut buf = AlignedBuf::<u64>::new();
+    buf.write_pod_slice(&[1u16, 2, 3]).unwrap();
+    buf.extend_from_slice(&[0; 2]);
+    assert!(buf.len() == 8 && buf.capacity() >= 8);
+    assert!(buf.as_pod_slice::<u16>().unwrap() == [1, 2, 3, 0]);
+    assert!(buf.as_pod_slice::<u64>().unwrap().len() == 1);
+    buf.as_pod_slice_mut::<u32>().unwrap()[1] = 0;
+    buf.truncate(4);
+    buf.resize(6);
+    assert!(buf[..] == *u16::slice_as_bytes(&[1, 2, 0]));
+
+    // Alignment is checked against the storage type, not the address
+    let mut bytes = AlignedBuf::<u8>::with_capacity(16);
+    bytes.resize(16);
+    assert!(bytes.capacity() == 16 && bytes.as_pod_slice::<u8>().unwrap().len() == 16);
+    assert!(bytes.as_pod_slice::<u16>().unwrap_err() == CastError::Layout { align: align_of::<u16>(), actual: 1 });
+
+    let words = (0..5000u32).collect::<Vec<_>>();
+    let read = u32::slice_as_bytes(&words[1..]).read_to_end_aligned::<u32>().unwrap();
+    assert!(read.as_pod_slice::<u32>().unwrap() == &words[1..]);
+    assert!(read.clone()[..] == read[..]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_read_boxed() {
+    use pod::PodReadExt;
+
+    let large = <[u64; 0x1000]>::new_box_zeroed();
+    assert!(large.iter().all(|&v| v == 0));
+    let mut uninit = <[u8; 3]>::new_box_uninit();
+    for (i, byte) in pod::as_uninit_bytes_mut(&mut *uninit).iter_mut().enumerate() {
+        *byte = std::mem::MaybeUninit::new(i as u8);
+    }
+    let init = unsafe { Box::from_raw(Box::into_raw(uninit) as *mut [u8; 3]) };
+    assert!(*init == [0, 1, 2]);
+
+    let bytes = vec![7u8; 0x8000];
+    let boxed = (&bytes[..]).read_pod_boxed::<[u64; 0x1000]>().unwrap();
+    assert!(boxed.iter().all(|&v| v == u64::from_ne_bytes([7; 8])));
+    assert!((&bytes[1..]).read_pod_boxed::<[u64; 0x1000]>().is_err());
 
-    assert!(un.map::<i16>().is_none());
-    assert!(un.map_mut::<i16>().is_none());
-    assert!(un.map_copy::<i16>().unwrap() == 0x0101i16);
-    assert!(un.try_map::<i16>().is_none());
-    assert!(un.try_map_mut::<i16>().is_none());
+    let mut value = 0u32;
+    (&[1, 1, 1, 1][..]).read_pod_into(&mut value).unwrap();
+    assert!(value == 0x01010101);
 }